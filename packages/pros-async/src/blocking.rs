@@ -0,0 +1,119 @@
+//! Running blocking code on FreeRTOS tasks.
+
+use alloc::sync::Arc;
+use core::{
+    cell::UnsafeCell,
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll},
+};
+
+use pros_core::task;
+
+use crate::{executor::EXECUTOR, futures::now_millis};
+
+/// How often a [`JoinHandle`] checks whether its task has finished.
+const JOIN_POLL_INTERVAL_MILLIS: u32 = 5;
+
+/// The state shared between a blocking task and its [`JoinHandle`].
+struct BlockingState<T> {
+    finished: AtomicBool,
+    output: UnsafeCell<Option<T>>,
+}
+
+// SAFETY: `output` is only written by the blocking task before `finished` is set,
+// and only read by the join handle after `finished` has been observed.
+unsafe impl<T: Send> Sync for BlockingState<T> {}
+
+/// A future that resolves to the return value of a closure passed to [`spawn_blocking`].
+///
+/// Dropping the handle does not stop the task. It will still run to completion, and its
+/// return value will be dropped.
+pub struct JoinHandle<T> {
+    state: Arc<BlockingState<T>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Returns `true` if the blocking task has finished running.
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.load(Ordering::Acquire)
+    }
+}
+
+impl<T> core::fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.is_finished() {
+            // SAFETY: The blocking task has finished, so it will never access `output` again.
+            let output = unsafe { (*self.state.output.get()).take() };
+            return Poll::Ready(output.expect("`JoinHandle` polled after completion"));
+        }
+
+        // Wakers can't be sent to other FreeRTOS tasks, so the reactor is used to
+        // check on the blocking task again after a short delay.
+        let target = now_millis().wrapping_add(JOIN_POLL_INTERVAL_MILLIS);
+        EXECUTOR.with(|e| {
+            e.reactor
+                .borrow_mut()
+                .sleepers
+                .push(cx.waker().clone(), target)
+        });
+        Poll::Pending
+    }
+}
+
+/// Runs a blocking closure on a new FreeRTOS task, returning a future that resolves to its return value.
+///
+/// This is useful for work that would otherwise stall every other future on the executor,
+/// such as heavy computation or long blocking reads from a device.
+/// Other futures continue to run while the closure is executing.
+///
+/// # Stack Size
+///
+/// The task is created with the default stack depth ([`TaskStackDepth::Default`](pros_core::task::TaskStackDepth::Default)).
+/// Closures that recurse deeply or keep large values on the stack can overflow it. In that case,
+/// move large values onto the heap (for example with a `Box` or `Vec`) before doing the work.
+///
+/// # Panics
+///
+/// Panics if there is not enough memory to create the task.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pros::async_runtime::spawn_blocking;
+///
+/// async fn example() {
+///     let sum = spawn_blocking(|| (0..1_000_000u64).sum::<u64>()).await;
+///     println!("{sum}");
+/// }
+/// ```
+pub fn spawn_blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> JoinHandle<T> {
+    let state = Arc::new(BlockingState {
+        finished: AtomicBool::new(false),
+        output: UnsafeCell::new(None),
+    });
+
+    let task_state = state.clone();
+    task::Builder::new()
+        .name("spawn_blocking")
+        .spawn(move || {
+            let output = f();
+            // SAFETY: The join handle won't read `output` until `finished` is set.
+            unsafe { *task_state.output.get() = Some(output) };
+            task_state.finished.store(true, Ordering::Release);
+        })
+        .expect("Failed to spawn blocking task");
+
+    JoinHandle { state }
+}
@@ -0,0 +1,151 @@
+//! Combinators for running several futures at the same time.
+
+use core::{
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A value that is one of two possible types.
+///
+/// Returned by [`select2`] to indicate which future completed first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<A, B> {
+    /// The first future completed first.
+    Left(A),
+    /// The second future completed first.
+    Right(B),
+}
+
+/// A future that completes when either of two futures completes.
+///
+/// Created by [`select2`].
+#[derive(Debug)]
+pub struct Select2<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Future, B: Future> Future for Select2<A, B> {
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: Neither future is ever moved out of the pinned struct.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // SAFETY: See above. `this` is pinned, so both futures are pinned as well.
+        if let Poll::Ready(output) = unsafe { Pin::new_unchecked(&mut this.a) }.poll(cx) {
+            return Poll::Ready(Either::Left(output));
+        }
+        if let Poll::Ready(output) = unsafe { Pin::new_unchecked(&mut this.b) }.poll(cx) {
+            return Poll::Ready(Either::Right(output));
+        }
+
+        Poll::Pending
+    }
+}
+
+/// Runs two futures at the same time, completing with the output of whichever finishes first.
+///
+/// The other future is dropped without being polled to completion. If both futures are ready
+/// at the same time, the first one wins.
+///
+/// Each future only registers its own wakeups (such as timers with the reactor), so a pending
+/// branch does not cause the executor to spin.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use pros::async_runtime::{select2, sleep, Either};
+///
+/// async fn example() {
+///     match select2(sleep(Duration::from_secs(1)), sleep(Duration::from_secs(2))).await {
+///         Either::Left(()) => println!("The first sleep finished first"),
+///         Either::Right(()) => unreachable!(),
+///     }
+/// }
+/// ```
+pub const fn select2<A: Future, B: Future>(a: A, b: B) -> Select2<A, B> {
+    Select2 { a, b }
+}
+
+/// The state of one of the futures being driven by a [`Join2`].
+enum MaybeDone<F: Future> {
+    Pending(F),
+    Done(F::Output),
+    Taken,
+}
+
+impl<F: Future> MaybeDone<F> {
+    /// Polls the inner future if it hasn't completed yet, returning `true` once it has.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be pinned.
+    unsafe fn poll(&mut self, cx: &mut Context<'_>) -> bool {
+        if let Self::Pending(future) = self {
+            // SAFETY: The caller guarantees that `self`, and therefore `future`, is pinned.
+            match unsafe { Pin::new_unchecked(future) }.poll(cx) {
+                Poll::Ready(output) => *self = Self::Done(output),
+                Poll::Pending => return false,
+            }
+        }
+        true
+    }
+
+    fn take_output(&mut self) -> F::Output {
+        match mem::replace(self, Self::Taken) {
+            Self::Done(output) => output,
+            _ => panic!("`Join2` polled after completion"),
+        }
+    }
+}
+
+/// A future that completes when both of two futures complete.
+///
+/// Created by [`join2`].
+pub struct Join2<A: Future, B: Future> {
+    a: MaybeDone<A>,
+    b: MaybeDone<B>,
+}
+
+impl<A: Future, B: Future> Future for Join2<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: Neither future is moved out of the pinned struct until it has completed.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // SAFETY: `this` is pinned, so both fields are pinned as well.
+        let a_done = unsafe { this.a.poll(cx) };
+        let b_done = unsafe { this.b.poll(cx) };
+
+        if a_done && b_done {
+            Poll::Ready((this.a.take_output(), this.b.take_output()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Runs two futures at the same time, completing with both of their outputs once both have finished.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use pros::async_runtime::{join2, sleep};
+///
+/// async fn example() {
+///     // Completes after one second rather than two.
+///     join2(sleep(Duration::from_secs(1)), sleep(Duration::from_secs(1))).await;
+/// }
+/// ```
+pub const fn join2<A: Future, B: Future>(a: A, b: B) -> Join2<A, B> {
+    Join2 {
+        a: MaybeDone::Pending(a),
+        b: MaybeDone::Pending(b),
+    }
+}
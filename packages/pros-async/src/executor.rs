@@ -1,8 +1,8 @@
 use alloc::{collections::VecDeque, sync::Arc};
 use core::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     future::Future,
-    pin::Pin,
+    pin::pin,
     sync::atomic::{AtomicBool, Ordering},
     task::{Context, Poll},
     time::Duration,
@@ -21,6 +21,34 @@ os_task_local! {
 pub(crate) struct Executor {
     queue: RefCell<VecDeque<Runnable>>,
     pub(crate) reactor: RefCell<Reactor>,
+    blocking: Cell<bool>,
+    active_tasks: Cell<usize>,
+}
+
+/// Clears the executor's `blocking` flag when a `block_on` call returns.
+struct BlockingGuard<'a>(&'a Cell<bool>);
+
+impl Drop for BlockingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
+}
+
+/// Tracks a spawned task, decrementing the executor's active task count when the task
+/// completes or is cancelled.
+struct ActiveTaskGuard(&'static Cell<usize>);
+
+impl ActiveTaskGuard {
+    fn new(active_tasks: &'static Cell<usize>) -> Self {
+        active_tasks.set(active_tasks.get() + 1);
+        Self(active_tasks)
+    }
+}
+
+impl Drop for ActiveTaskGuard {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
+    }
 }
 
 impl !Send for Executor {}
@@ -31,10 +59,20 @@ impl Executor {
         Self {
             queue: RefCell::new(VecDeque::new()),
             reactor: RefCell::new(Reactor::new()),
+            blocking: Cell::new(false),
+            active_tasks: Cell::new(0),
         }
     }
 
     pub fn spawn<T>(&'static self, future: impl Future<Output = T> + 'static) -> Task<T> {
+        // The guard is moved into the task so that it is dropped even if the task is
+        // cancelled before it is first polled.
+        let guard = ActiveTaskGuard::new(&self.active_tasks);
+        let future = async move {
+            let _guard = guard;
+            future.await
+        };
+
         // SAFETY: `runnable` will never be moved off this thread or shared with another thread because of the `!Send + !Sync` bounds on `Self`.
         //         Both `future` and `schedule` are `'static` so they cannot be used after being freed.
         //   TODO: Make sure that the waker can never be sent off the thread.
@@ -65,7 +103,20 @@ impl Executor {
         }
     }
 
-    pub fn block_on<R>(&self, mut task: Task<R>) -> R {
+    /// Marks the executor as blocking the current task until the returned guard is dropped.
+    fn enter_blocking(&self) -> BlockingGuard<'_> {
+        assert!(
+            !self.blocking.replace(true),
+            "`block_on` and `complete` cannot be called while another `block_on` or `complete` call is running on the same task"
+        );
+        BlockingGuard(&self.blocking)
+    }
+
+    pub fn block_on<R>(&self, future: impl Future<Output = R>) -> R {
+        let _blocking_guard = self.enter_blocking();
+
+        let mut future = pin!(future);
+
         let woken = Arc::new(AtomicBool::new(true));
 
         let waker = waker_fn({
@@ -76,7 +127,7 @@ impl Executor {
 
         loop {
             if woken.swap(false, Ordering::Relaxed) {
-                if let Poll::Ready(output) = Pin::new(&mut task).poll(&mut cx) {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
                     return output;
                 }
                 self.tick();
@@ -84,8 +135,21 @@ impl Executor {
                 continue;
             }
 
-            delay(Duration::from_millis(10));
-            self.tick();
+            // only sleep if there are no other tasks ready to run
+            if !self.tick() {
+                delay(Duration::from_millis(1));
+            }
+        }
+    }
+
+    pub fn complete(&self) {
+        let _blocking_guard = self.enter_blocking();
+
+        while self.active_tasks.get() > 0 {
+            // only sleep if there are no tasks ready to run
+            if !self.tick() {
+                delay(Duration::from_millis(1));
+            }
         }
     }
 }
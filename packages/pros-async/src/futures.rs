@@ -0,0 +1,305 @@
+//! Timer-based futures that are driven by the executor's reactor.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use pros_core::time::Instant;
+use snafu::Snafu;
+
+use crate::executor::EXECUTOR;
+
+/// Returns the number of milliseconds since the user program was started.
+pub(crate) fn now_millis() -> u32 {
+    unsafe { pros_sys::millis() }
+}
+
+/// Converts a duration to milliseconds, rounding up so that sub-millisecond
+/// durations still yield to the executor.
+///
+/// Durations are clamped to [`i32::MAX`] milliseconds (~24 days), which is the
+/// longest span that can be compared across a wrap of the millisecond clock.
+fn duration_to_millis(duration: Duration) -> u32 {
+    let millis = duration.as_micros().div_ceil(1000);
+    millis.min(i32::MAX as u128) as u32
+}
+
+/// Returns `true` if the millisecond timestamp `target` is at or before `now`.
+///
+/// The comparison is done using wrapping arithmetic so that it stays correct
+/// when the millisecond clock overflows after ~49 days of uptime.
+pub(crate) const fn is_elapsed(now: u32, target: u32) -> bool {
+    now.wrapping_sub(target) as i32 >= 0
+}
+
+/// A future that will complete after the given duration.
+/// Every sleep future whose deadline has passed is woken on the next tick of the executor.
+#[derive(Debug)]
+pub struct SleepFuture {
+    target_millis: u32,
+}
+
+impl Future for SleepFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if is_elapsed(now_millis(), self.target_millis) {
+            Poll::Ready(())
+        } else {
+            EXECUTOR.with(|e| {
+                e.reactor
+                    .borrow_mut()
+                    .sleepers
+                    .push(cx.waker().clone(), self.target_millis)
+            });
+            Poll::Pending
+        }
+    }
+}
+
+/// Returns a future that will complete after the given duration.
+///
+/// Durations that are not a whole number of milliseconds are rounded up.
+pub fn sleep(duration: Duration) -> SleepFuture {
+    SleepFuture {
+        target_millis: now_millis().wrapping_add(duration_to_millis(duration)),
+    }
+}
+
+/// Returns a future that will complete once the given instant has been reached.
+///
+/// If `deadline` is in the past, the future will complete immediately.
+pub fn sleep_until(deadline: Instant) -> SleepFuture {
+    sleep(deadline.duration_since(Instant::now()))
+}
+
+/// How often a [`WaitUntilFuture`] re-checks its predicate.
+const WAIT_UNTIL_POLL_INTERVAL_MILLIS: u32 = 5;
+
+/// A future that completes once a predicate returns `true`.
+///
+/// Created by [`wait_until`].
+#[derive(Debug)]
+pub struct WaitUntilFuture<F> {
+    predicate: F,
+}
+
+// The predicate is never pinned, so the future can be moved freely.
+impl<F> Unpin for WaitUntilFuture<F> {}
+
+impl<F: FnMut() -> bool> Future for WaitUntilFuture<F> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if (self.get_mut().predicate)() {
+            return Poll::Ready(());
+        }
+
+        // Rather than waking immediately and spinning, ask the reactor to wake
+        // this future again after a short delay.
+        let target = now_millis().wrapping_add(WAIT_UNTIL_POLL_INTERVAL_MILLIS);
+        EXECUTOR.with(|e| {
+            e.reactor
+                .borrow_mut()
+                .sleepers
+                .push(cx.waker().clone(), target)
+        });
+        Poll::Pending
+    }
+}
+
+/// Returns a future that completes once `predicate` returns `true`.
+///
+/// The predicate is checked when the future is first polled, and then every few milliseconds
+/// until it returns `true`. Other tasks continue to run while waiting.
+pub const fn wait_until<F: FnMut() -> bool>(predicate: F) -> WaitUntilFuture<F> {
+    WaitUntilFuture { predicate }
+}
+
+/// Defines the behavior of an [`Interval`] when it misses a tick.
+///
+/// A tick is missed when [`Interval::tick`] is called after the tick's deadline
+/// has already passed, usually because the loop body took longer than the period.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MissedTickBehavior {
+    /// Ticks as fast as possible until the interval has caught up, keeping
+    /// the same total number of ticks as if none had been missed.
+    #[default]
+    Burst,
+
+    /// Completes the missed tick immediately, then skips any other missed ticks
+    /// so that following ticks stay aligned to multiples of the period.
+    Skip,
+}
+
+/// A periodic timer that ticks at a fixed rate without drifting.
+///
+/// Each deadline is computed from the previous deadline rather than from the time that
+/// [`Interval::tick`] was called, so the time spent between ticks does not accumulate.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use pros::async_runtime::Interval;
+///
+/// async fn control_loop() {
+///     let mut interval = Interval::new(Duration::from_millis(10));
+///     loop {
+///         // This loop body will run every 10ms.
+///         interval.tick().await;
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Interval {
+    next_millis: u32,
+    period_millis: u32,
+    missed_tick_behavior: MissedTickBehavior,
+}
+
+impl Interval {
+    /// Creates a new interval with the given period.
+    /// The first tick completes immediately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is shorter than one millisecond.
+    pub fn new(period: Duration) -> Self {
+        Self::new_at(Instant::now(), period)
+    }
+
+    /// Creates a new interval with the given period that first ticks at `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is shorter than one millisecond.
+    pub fn new_at(start: Instant, period: Duration) -> Self {
+        assert!(
+            period >= Duration::from_millis(1),
+            "interval period must be at least one millisecond"
+        );
+
+        let until_start = duration_to_millis(start.duration_since(Instant::now()));
+        Self {
+            next_millis: now_millis().wrapping_add(until_start),
+            period_millis: duration_to_millis(period),
+            missed_tick_behavior: MissedTickBehavior::default(),
+        }
+    }
+
+    /// Returns the period of the interval.
+    pub const fn period(&self) -> Duration {
+        Duration::from_millis(self.period_millis as u64)
+    }
+
+    /// Returns the behavior of the interval when a tick is missed.
+    pub const fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    /// Sets the behavior of the interval when a tick is missed.
+    pub fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.missed_tick_behavior = behavior;
+    }
+
+    /// Returns a future that completes when the next tick of the interval is reached.
+    pub fn tick(&mut self) -> SleepFuture {
+        let deadline = self.next_millis;
+        let now = now_millis();
+
+        self.next_millis = match self.missed_tick_behavior {
+            MissedTickBehavior::Skip if is_elapsed(now, deadline) => {
+                let missed_ticks = now.wrapping_sub(deadline) / self.period_millis + 1;
+                deadline.wrapping_add(missed_ticks * self.period_millis)
+            }
+            _ => deadline.wrapping_add(self.period_millis),
+        };
+
+        SleepFuture {
+            target_millis: deadline,
+        }
+    }
+}
+
+/// The error returned when a [`Timeout`] elapses before its future completes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Snafu)]
+#[snafu(display("The future did not complete before the timeout elapsed."))]
+pub struct TimeoutError;
+
+/// A future that races another future against a deadline.
+///
+/// Created by [`timeout`] and [`with_deadline`].
+#[derive(Debug)]
+pub struct Timeout<F> {
+    future: Option<F>,
+    sleep: SleepFuture,
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, TimeoutError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `future` is never moved out of the pinned struct. It is only
+        // dropped in place once the deadline has been reached.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        let future = this
+            .future
+            .as_mut()
+            .expect("`Timeout` polled after it timed out");
+        // SAFETY: See above. `this` is pinned, so `future` is pinned as well.
+        if let Poll::Ready(output) = unsafe { Pin::new_unchecked(future) }.poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+
+        match Pin::new(&mut this.sleep).poll(cx) {
+            Poll::Ready(()) => {
+                // Drop the inner future now rather than when the `Timeout` is dropped.
+                this.future = None;
+                Poll::Ready(Err(TimeoutError))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Requires a future to complete within the given duration.
+///
+/// If the future completes first, its output is returned. Otherwise, the future is
+/// dropped and [`TimeoutError`] is returned. The inner future is always polled before
+/// the timer, so a future that is immediately ready will complete even with a zero duration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use pros::async_runtime::{sleep, timeout};
+///
+/// async fn example() {
+///     let result = timeout(Duration::from_millis(10), sleep(Duration::from_secs(1))).await;
+///     assert!(result.is_err());
+/// }
+/// ```
+pub fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+    Timeout {
+        future: Some(future),
+        sleep: sleep(duration),
+    }
+}
+
+/// Requires a future to complete before the given millisecond timestamp.
+///
+/// `deadline_millis` is measured in milliseconds since the user program was started
+/// (the same clock as [`pros_sys::millis`]). See [`timeout`] for more details.
+pub const fn with_deadline<F: Future>(deadline_millis: u32, future: F) -> Timeout<F> {
+    Timeout {
+        future: Some(future),
+        sleep: SleepFuture {
+            target_millis: deadline_millis,
+        },
+    }
+}
@@ -9,59 +9,56 @@
 
 extern crate alloc;
 
-use core::{future::Future, task::Poll};
+use core::future::Future;
 
-use async_task::Task;
-use executor::EXECUTOR;
 use pros_core::error::Result;
 
+mod blocking;
+mod combinators;
 mod executor;
+mod futures;
 mod reactor;
+mod task;
+
+pub use blocking::*;
+pub use combinators::*;
+pub use futures::*;
+pub use task::Task;
 
 /// Runs a future in the background without having to await it
 /// To get the the return value you can await a task.
+///
+/// The returned [`Task`] cancels the future when dropped unless it is [detached](Task::detach).
 pub fn spawn<T>(future: impl Future<Output = T> + 'static) -> Task<T> {
-    executor::EXECUTOR.with(|e| e.spawn(future))
+    Task::new(executor::EXECUTOR.with(|e| e.spawn(future)))
 }
 
 /// Blocks the current task untill a return value can be extracted from the provided future.
-/// Does not poll all futures to completion.
-pub fn block_on<F: Future + 'static>(future: F) -> F::Output {
-    executor::EXECUTOR.with(|e| e.block_on(spawn(future)))
-}
-
-/// A future that will complete after the given duration.
-/// Sleep futures that are closer to completion are prioritized to improve accuracy.
-#[derive(Debug)]
-pub struct SleepFuture {
-    target_millis: u32,
-}
-impl Future for SleepFuture {
-    type Output = ();
-
-    fn poll(
-        self: core::pin::Pin<&mut Self>,
-        cx: &mut core::task::Context<'_>,
-    ) -> core::task::Poll<Self::Output> {
-        if self.target_millis < unsafe { pros_sys::millis() } {
-            Poll::Ready(())
-        } else {
-            EXECUTOR.with(|e| {
-                e.reactor
-                    .borrow_mut()
-                    .sleepers
-                    .push(cx.waker().clone(), self.target_millis)
-            });
-            Poll::Pending
-        }
-    }
+/// Does not poll all futures to completion. Use [`complete`] to wait for every spawned task instead.
+///
+/// The future is polled in place, so it does not need to be `'static`.
+/// Spawned tasks and timers continue to be driven while waiting on the future.
+///
+/// # Panics
+///
+/// Panics if called from within a future that is being driven by `block_on` on the same task.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    executor::EXECUTOR.with(|e| e.block_on(future))
 }
 
-/// Returns a future that will complete after the given duration.
-pub fn sleep(duration: core::time::Duration) -> SleepFuture {
-    SleepFuture {
-        target_millis: unsafe { pros_sys::millis() + duration.as_millis() as u32 },
-    }
+/// Blocks the current task until every task created with [`spawn`] has completed.
+///
+/// Unlike [`block_on`], which only waits for one future (leaving any other spawned tasks unfinished
+/// once it returns), this drives the executor until there are no spawned tasks left.
+/// Tasks that are spawned while waiting are also waited on.
+///
+/// Tasks that never complete will cause this function to block forever.
+///
+/// # Panics
+///
+/// Panics if called from within a future that is being driven by [`block_on`] or `complete` on the same task.
+pub fn complete() {
+    executor::EXECUTOR.with(|e| e.complete())
 }
 
 /// A trait for robot code that spins up the pros-rs async executor.
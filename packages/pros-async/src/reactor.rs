@@ -1,17 +1,29 @@
-use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
 use core::task::Waker;
 
+use crate::futures::{is_elapsed, now_millis};
+
 pub struct Sleepers {
-    sleepers: BTreeMap<u32, Waker>,
+    sleepers: Vec<(u32, Waker)>,
 }
 
 impl Sleepers {
     pub fn push(&mut self, waker: Waker, target: u32) {
-        self.sleepers.insert(target, waker);
+        self.sleepers.push((target, waker));
     }
 
-    pub fn pop(&mut self) -> Option<Waker> {
-        self.sleepers.pop_first().map(|(_, waker)| waker)
+    /// Wakes every sleeper whose target time has been reached, leaving the rest queued.
+    pub fn wake_due(&mut self, now: u32) {
+        // Targets are compared with wrapping arithmetic rather than sorted, so that sleepers
+        // on either side of a millisecond clock overflow are still woken in time.
+        self.sleepers.retain(|(target, waker)| {
+            if is_elapsed(now, *target) {
+                waker.wake_by_ref();
+                false
+            } else {
+                true
+            }
+        });
     }
 }
 
@@ -23,14 +35,12 @@ impl Reactor {
     pub const fn new() -> Self {
         Self {
             sleepers: Sleepers {
-                sleepers: BTreeMap::new(),
+                sleepers: Vec::new(),
             },
         }
     }
 
     pub fn tick(&mut self) {
-        if let Some(sleeper) = self.sleepers.pop() {
-            sleeper.wake()
-        }
+        self.sleepers.wake_due(now_millis());
     }
 }
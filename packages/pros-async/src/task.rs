@@ -0,0 +1,103 @@
+//! Handles to tasks spawned on the async executor.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use waker_fn::waker_fn;
+
+/// A handle to a task spawned with [`spawn`](crate::spawn).
+///
+/// Awaiting the handle waits for the task to complete and returns its output.
+///
+/// # Cancellation
+///
+/// Dropping the handle cancels the task, just like calling [`Task::cancel`].
+/// Use [`Task::detach`] to let the task keep running in the background instead.
+///
+/// A cancelled task is never polled again. Its future (along with anything it owns, such as devices)
+/// is dropped the next time the executor runs, and the task no longer counts towards [`complete`](crate::complete).
+#[derive(Debug)]
+#[must_use = "tasks are cancelled when dropped, use `.detach()` to run them in the background"]
+pub struct Task<T> {
+    task: async_task::Task<T>,
+}
+
+impl<T> Task<T> {
+    pub(crate) const fn new(task: async_task::Task<T>) -> Self {
+        Self { task }
+    }
+
+    /// Returns `true` if the task has completed.
+    ///
+    /// This does not block or poll the task.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Returns the output of the task if it has completed, or gives the handle back if it hasn't.
+    ///
+    /// Unlike awaiting the handle, this never blocks, so it can be used to check on several
+    /// background tasks from a loop.
+    pub fn try_join(mut self) -> Result<T, Self> {
+        if !self.is_finished() {
+            return Err(self);
+        }
+
+        // The task has already completed, so polling it once will yield its output
+        // without needing to be woken.
+        let waker = waker_fn(|| {});
+        let mut cx = Context::from_waker(&waker);
+        match Pin::new(&mut self.task).poll(&mut cx) {
+            Poll::Ready(output) => Ok(output),
+            Poll::Pending => Err(self),
+        }
+    }
+
+    /// Cancels the task, returning its output if it had already completed.
+    ///
+    /// If the task has not completed, it will never be polled again and its future is dropped
+    /// the next time the executor runs. Cancelling a task that has already completed
+    /// has no effect other than returning its output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use core::time::Duration;
+    /// use pros::async_runtime::{sleep, spawn};
+    ///
+    /// async fn auton() {
+    ///     let tracking = spawn(async {
+    ///         loop {
+    ///             // Update odometry...
+    ///             sleep(Duration::from_millis(10)).await;
+    ///         }
+    ///     });
+    ///
+    ///     // Run the autonomous routine...
+    ///
+    ///     tracking.cancel();
+    /// }
+    /// ```
+    pub fn cancel(self) -> Option<T> {
+        // Dropping the inner task cancels it if it hasn't completed yet.
+        self.try_join().ok()
+    }
+
+    /// Detaches the task, letting it run in the background until it completes.
+    ///
+    /// The task's output is dropped once it completes.
+    pub fn detach(self) {
+        self.task.detach();
+    }
+}
+
+impl<T> Future for Task<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.task).poll(cx)
+    }
+}
@@ -105,6 +105,23 @@ pub enum PortError {
     AlreadyInUse,
 }
 
+impl From<PortError> for no_std_io::io::Error {
+    fn from(err: PortError) -> Self {
+        use no_std_io::io::ErrorKind;
+
+        match err {
+            PortError::PortOutOfRange => {
+                Self::new(ErrorKind::AddrNotAvailable, "port out of range")
+            }
+            PortError::PortCannotBeConfigured => Self::new(
+                ErrorKind::AddrNotAvailable,
+                "port cannot be configured as the specified type",
+            ),
+            PortError::AlreadyInUse => Self::new(ErrorKind::AddrInUse, "port already in use"),
+        }
+    }
+}
+
 map_errno!(PortError {
     ENXIO => Self::PortOutOfRange,
     ENODEV => Self::PortCannotBeConfigured,
@@ -0,0 +1,342 @@
+//! Files on the V5 Brain's SD card.
+//!
+//! The SD card is mounted at `/usd/`, so every path passed to this module should start with it.
+//! Files implement the [`Read`], [`Write`] and [`Seek`] traits from [`io`](crate::io).
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use pros::core::{fs::File, io::Write};
+//!
+//! let mut log = File::create("/usd/log.txt").unwrap();
+//! log.write_all(b"Hello, SD card!\n").unwrap();
+//! ```
+//!
+//! # Limitations
+//!
+//! PROS does not expose directory listing, so there is no way to list the files on the SD card.
+
+use alloc::ffi::CString;
+use core::ffi::{c_long, CStr};
+
+use no_std_io::io::{self, Read, Seek, SeekFrom, Write};
+use snafu::Snafu;
+
+use crate::{
+    error::{take_errno, FromErrno},
+    map_errno,
+};
+
+/// Returns `true` if an SD card is inserted into the brain.
+pub fn sd_card_installed() -> bool {
+    unsafe { pros_sys::usd_is_installed() == 1 }
+}
+
+/// Options for how a [`File`] should be opened.
+///
+/// This mirrors [`std::fs::OpenOptions`](https://doc.rust-lang.org/std/fs/struct.OpenOptions.html),
+/// but only supports the combinations of options that newlib's `fopen` can represent.
+/// Unsupported combinations fail with [`FsError::InvalidOptions`].
+///
+/// | Options                         | Behavior                                               |
+/// |---------------------------------|--------------------------------------------------------|
+/// | `read`                          | Opens an existing file for reading.                    |
+/// | `write`                         | Opens an existing file for reading and writing.        |
+/// | `write`, `create`, `truncate`   | Creates or empties a file for writing.                 |
+/// | `append`, `create`              | Creates a file if needed and writes to its end.        |
+///
+/// Adding `read` to the last two rows also allows reading from the file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OpenOptions {
+    read: bool,
+    write: bool,
+    append: bool,
+    create: bool,
+    truncate: bool,
+}
+
+impl OpenOptions {
+    /// Creates a set of options with every option disabled.
+    pub const fn new() -> Self {
+        Self {
+            read: false,
+            write: false,
+            append: false,
+            create: false,
+            truncate: false,
+        }
+    }
+
+    /// Sets whether the file can be read from.
+    pub const fn read(mut self, read: bool) -> Self {
+        self.read = read;
+        self
+    }
+
+    /// Sets whether the file can be written to.
+    pub const fn write(mut self, write: bool) -> Self {
+        self.write = write;
+        self
+    }
+
+    /// Sets whether every write should go to the end of the file.
+    ///
+    /// This implies [`OpenOptions::write`].
+    pub const fn append(mut self, append: bool) -> Self {
+        self.append = append;
+        self
+    }
+
+    /// Sets whether the file should be created if it doesn't exist.
+    pub const fn create(mut self, create: bool) -> Self {
+        self.create = create;
+        self
+    }
+
+    /// Sets whether the file should be emptied when it is opened.
+    pub const fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Gets the `fopen` mode string for these options.
+    const fn mode(&self) -> Result<&'static CStr, FsError> {
+        let Self {
+            read,
+            write,
+            append,
+            create,
+            truncate,
+        } = *self;
+
+        Ok(match (read, write, append, create, truncate) {
+            (true, false, false, false, false) => c"rb",
+            (_, true, false, false, false) => c"r+b",
+            (false, true, false, true, true) => c"wb",
+            (true, true, false, true, true) => c"w+b",
+            (false, _, true, true, false) => c"ab",
+            (true, _, true, true, false) => c"a+b",
+            _ => return Err(FsError::InvalidOptions),
+        })
+    }
+
+    /// Opens the file at `path` with these options.
+    pub fn open(&self, path: &str) -> Result<File, FsError> {
+        let mode = self.mode()?;
+        if !sd_card_installed() {
+            return Err(FsError::NoSdCard);
+        }
+
+        let path = CString::new(path).map_err(|_| FsError::InvalidPath)?;
+
+        let stream = unsafe { pros_sys::fopen(path.as_ptr(), mode.as_ptr()) };
+        if stream.is_null() {
+            return Err(FsError::last());
+        }
+
+        Ok(File { stream })
+    }
+}
+
+/// Information about a [`File`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    len: u64,
+}
+
+impl Metadata {
+    /// Returns the size of the file in bytes.
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the file is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// An open file on the SD card.
+///
+/// Reads and writes are buffered by newlib, so call [`Write::flush`] to make sure that data has
+/// been written to the card. The file is flushed and closed when it is dropped.
+#[derive(Debug)]
+pub struct File {
+    stream: *mut pros_sys::FILE,
+}
+unsafe impl Send for File {}
+
+impl File {
+    /// Opens an existing file for reading.
+    pub fn open(path: &str) -> Result<Self, FsError> {
+        OpenOptions::new().read(true).open(path)
+    }
+
+    /// Opens a file for writing, creating it if it doesn't exist and emptying it if it does.
+    pub fn create(path: &str) -> Result<Self, FsError> {
+        OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+    }
+
+    /// Opens a file for appending, creating it if it doesn't exist.
+    pub fn append(path: &str) -> Result<Self, FsError> {
+        OpenOptions::new().append(true).create(true).open(path)
+    }
+
+    /// Gets information about the file, such as its size.
+    pub fn metadata(&mut self) -> Result<Metadata, FsError> {
+        let position = self.position()?;
+        self.seek_raw(0, pros_sys::SEEK_END)?;
+        let len = self.position()?;
+        self.seek_raw(position as c_long, pros_sys::SEEK_SET)?;
+
+        Ok(Metadata { len })
+    }
+
+    /// Returns the current position of the cursor in the file.
+    fn position(&mut self) -> Result<u64, FsError> {
+        match unsafe { pros_sys::ftell(self.stream) } {
+            -1 => Err(FsError::last()),
+            position => Ok(position as u64),
+        }
+    }
+
+    fn seek_raw(&mut self, offset: c_long, whence: i32) -> Result<(), FsError> {
+        if unsafe { pros_sys::fseek(self.stream, offset, whence) } != 0 {
+            return Err(FsError::last());
+        }
+        Ok(())
+    }
+
+    /// Returns the error that caused the last read or write to come up short, if there was one.
+    fn take_stream_error(&mut self) -> Option<FsError> {
+        if unsafe { pros_sys::ferror(self.stream) } == 0 {
+            return None;
+        }
+
+        unsafe { pros_sys::clearerr(self.stream) };
+        Some(FsError::last())
+    }
+}
+
+impl Read for File {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = unsafe { pros_sys::fread(buf.as_mut_ptr().cast(), 1, buf.len(), self.stream) };
+
+        match self.take_stream_error() {
+            Some(err) if read == 0 => Err(err.into()),
+            _ => Ok(read),
+        }
+    }
+}
+
+impl Write for File {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = unsafe { pros_sys::fwrite(buf.as_ptr().cast(), 1, buf.len(), self.stream) };
+
+        match self.take_stream_error() {
+            Some(err) if written == 0 && !buf.is_empty() => Err(err.into()),
+            _ => Ok(written),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if unsafe { pros_sys::fflush(self.stream) } != 0 {
+            return Err(FsError::last().into());
+        }
+        Ok(())
+    }
+}
+
+impl Seek for File {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (offset, whence) = match pos {
+            SeekFrom::Start(offset) => (offset as i64, pros_sys::SEEK_SET),
+            SeekFrom::End(offset) => (offset, pros_sys::SEEK_END),
+            SeekFrom::Current(offset) => (offset, pros_sys::SEEK_CUR),
+        };
+        let offset = c_long::try_from(offset)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "seek offset too large"))?;
+
+        self.seek_raw(offset, whence)?;
+        Ok(self.position()?)
+    }
+}
+
+impl Drop for File {
+    fn drop(&mut self) {
+        // fclose flushes any buffered writes before closing the file.
+        unsafe {
+            pros_sys::fclose(self.stream);
+        }
+    }
+}
+
+#[derive(Debug, Snafu)]
+/// Errors that can occur when using files on the SD card.
+pub enum FsError {
+    /// No SD card is inserted into the brain.
+    NoSdCard,
+    /// The file does not exist.
+    NotFound,
+    /// The SD card is full.
+    Full,
+    /// The file is read-only or can't be accessed.
+    PermissionDenied,
+    /// Too many files are open at once.
+    TooManyOpenFiles,
+    /// The path contains a nul byte.
+    InvalidPath,
+    /// The combination of [`OpenOptions`] is not supported.
+    InvalidOptions,
+    /// An unknown error occurred (errno {errno}).
+    Unknown {
+        /// The raw errno value.
+        errno: i32,
+    },
+}
+
+impl FsError {
+    /// Creates an error from the current value of errno.
+    fn last() -> Self {
+        match take_errno() {
+            0 => Self::Unknown { errno: 0 },
+            errno => Self::from_errno(errno).unwrap_or(Self::Unknown { errno }),
+        }
+    }
+}
+
+map_errno! {
+    FsError {
+        ENOENT => Self::NotFound,
+        ENOSPC => Self::Full,
+        EACCES => Self::PermissionDenied,
+        EROFS => Self::PermissionDenied,
+        EMFILE => Self::TooManyOpenFiles,
+        ENFILE => Self::TooManyOpenFiles,
+    }
+}
+
+impl From<FsError> for io::Error {
+    fn from(err: FsError) -> Self {
+        use io::ErrorKind;
+
+        match err {
+            FsError::NoSdCard => Self::new(ErrorKind::NotConnected, "no SD card is inserted"),
+            FsError::NotFound => Self::new(ErrorKind::NotFound, "file not found"),
+            FsError::Full => Self::new(ErrorKind::Other, "SD card is full"),
+            FsError::PermissionDenied => {
+                Self::new(ErrorKind::PermissionDenied, "permission denied")
+            }
+            FsError::TooManyOpenFiles => Self::new(ErrorKind::Other, "too many open files"),
+            FsError::InvalidPath => Self::new(ErrorKind::InvalidInput, "path contains a nul byte"),
+            FsError::InvalidOptions => {
+                Self::new(ErrorKind::InvalidInput, "unsupported open options")
+            }
+            FsError::Unknown { .. } => Self::new(ErrorKind::Other, "unknown SD card error"),
+        }
+    }
+}
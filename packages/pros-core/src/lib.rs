@@ -4,6 +4,7 @@
 //! Included in this crate:
 //! - Global allocator: [`pros_alloc`]
 //! - Errno handling: [`error`]
+//! - SD card files: [`fs`]
 //! - Serial terminal printing: [`io`]
 //! - No-std [`Instant`](time::Instant)s: [`time`]
 //! - Synchronization primitives: [`sync`]
@@ -16,6 +17,7 @@ extern crate alloc;
 
 pub mod allocator;
 pub mod error;
+pub mod fs;
 pub mod io;
 pub mod sync;
 pub mod task;
@@ -2,12 +2,28 @@
 //!
 //! Types implemented here are specifically designed to mimic the standard library.
 
-use core::{cell::UnsafeCell, fmt::Debug, mem};
+use core::{
+    cell::UnsafeCell,
+    fmt::Debug,
+    future::{poll_fn, Future},
+    mem::{self, MaybeUninit},
+    ops::Deref,
+    sync::atomic::{AtomicU32, AtomicU8, Ordering},
+    task::Poll,
+    time::Duration,
+};
 
-use crate::error::take_errno;
+use crate::{error::take_errno, task::delay};
 
 /// The basic mutex type.
 /// Mutexes are used to share variables between tasks safely.
+///
+/// # Poisoning
+///
+/// Unlike [`std::sync::Mutex`](https://doc.rust-lang.org/std/sync/struct.Mutex.html),
+/// this mutex is never poisoned. Programs built for the V5 brain abort on panic, so a
+/// task that panics while holding a lock ends the entire program rather than leaving
+/// the data behind it in an inconsistent state for other tasks to observe.
 pub struct Mutex<T> {
     pros_mutex: pros_sys::mutex_t,
     data: Option<UnsafeCell<T>>,
@@ -28,6 +44,13 @@ impl<T> Mutex<T> {
 
     /// Locks the mutex so that it cannot be locked in another task at the same time.
     /// Blocks the current task until the lock is acquired.
+    ///
+    /// While waiting, the task is suspended by the FreeRTOS scheduler rather than spinning,
+    /// so other tasks (including the one holding the lock) keep running. The task holding the
+    /// lock also temporarily inherits the priority of the highest priority task waiting on it.
+    ///
+    /// Because this blocks the whole task, calling it from async code also blocks every other
+    /// future on the executor until the lock is acquired. Avoid holding a guard across an `.await`.
     pub fn lock(&self) -> MutexGuard<'_, T> {
         if !unsafe { pros_sys::mutex_take(self.pros_mutex, pros_sys::TIMEOUT_MAX) } {
             panic!("Mutex lock failed: {}", take_errno());
@@ -37,11 +60,35 @@ impl<T> Mutex<T> {
     }
 
     /// Attempts to acquire this lock. This function does not block.
+    ///
+    /// Returns `None` if the mutex is already locked.
     pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
         let success = unsafe { pros_sys::mutex_take(self.pros_mutex, 0) };
         success.then(|| MutexGuard::new(self))
     }
 
+    /// Attempts to acquire this lock, blocking the current task for at most `timeout`.
+    ///
+    /// Returns `None` if the lock could not be acquired before the timeout elapsed.
+    /// Like [`Mutex::lock`], the task is suspended rather than spinning while it waits.
+    pub fn lock_timeout(&self, timeout: Duration) -> Option<MutexGuard<'_, T>> {
+        // `TIMEOUT_MAX` means to wait forever, so longer timeouts are clamped just below it.
+        let timeout = timeout.as_millis().min((pros_sys::TIMEOUT_MAX - 1) as u128) as u32;
+        let success = unsafe { pros_sys::mutex_take(self.pros_mutex, timeout) };
+        success.then(|| MutexGuard::new(self))
+    }
+
+    /// Returns `true` if the mutex is currently locked, including by the current task.
+    ///
+    /// This only checks which task owns the mutex, so it never takes the lock itself and
+    /// can't cause a concurrent [`Mutex::try_lock`] to fail.
+    ///
+    /// The mutex may be locked or unlocked by another task immediately after this returns,
+    /// so prefer [`Mutex::try_lock`] when the data is going to be accessed.
+    pub fn is_locked(&self) -> bool {
+        !unsafe { pros_sys::apix::mutex_get_owner(self.pros_mutex) }.is_null()
+    }
+
     /// Consumes the mutex and returns the inner data.
     pub fn into_inner(mut self) -> T {
         let data = mem::take(&mut self.data).unwrap();
@@ -131,3 +178,487 @@ impl<T> Drop for MutexGuard<'_, T> {
         }
     }
 }
+
+/// The state of an [`RwLock`] that is locked for writing.
+const WRITE_LOCKED: u32 = u32::MAX;
+
+/// A reader-writer lock.
+///
+/// This lock allows any number of readers or at most one writer at any point in time.
+/// It is useful for data that is read by many tasks but only occasionally written to,
+/// such as a shared odometry pose, since readers do not block each other.
+///
+/// Unlike [`Mutex`], this is not backed by a FreeRTOS primitive, since FreeRTOS mutexes must
+/// be released by the task that took them. Tasks waiting on the lock instead sleep for a
+/// millisecond between attempts to acquire it. Waiting writers do not block new readers, so a
+/// lock that always has at least one reader can delay writers indefinitely.
+///
+/// # Poisoning
+///
+/// Like [`Mutex`], this lock is never poisoned, since panics abort the entire program.
+pub struct RwLock<T> {
+    state: AtomicU32,
+    data: UnsafeCell<T>,
+}
+unsafe impl<T: Send> Send for RwLock<T> {}
+unsafe impl<T: Send + Sync> Sync for RwLock<T> {}
+
+impl<T> RwLock<T> {
+    /// Creates a new reader-writer lock.
+    pub const fn new(data: T) -> Self {
+        Self {
+            state: AtomicU32::new(0),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    /// Locks the lock with shared read access.
+    /// Blocks the current task until no other task holds write access.
+    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+        loop {
+            if let Some(guard) = self.try_read() {
+                return guard;
+            }
+            delay(Duration::from_millis(1));
+        }
+    }
+
+    /// Locks the lock with shared read access from async code.
+    ///
+    /// Unlike [`RwLock::read`], other futures get to run between attempts. However, each retry
+    /// blocks the executor's task for 1ms before yielding, which stalls every future on that
+    /// executor, so prefer [`RwLock::try_read`] in loops that need tight timing.
+    pub async fn read_async(&self) -> RwLockReadGuard<'_, T> {
+        loop {
+            if let Some(guard) = self.try_read() {
+                return guard;
+            }
+            backoff().await;
+        }
+    }
+
+    /// Attempts to acquire this lock with shared read access. This function does not block.
+    ///
+    /// Returns `None` if another task holds write access.
+    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+        let mut state = self.state.load(Ordering::Relaxed);
+        loop {
+            // The reader count must never reach `WRITE_LOCKED`.
+            if state >= WRITE_LOCKED - 1 {
+                return None;
+            }
+
+            match self.state.compare_exchange_weak(
+                state,
+                state + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Some(RwLockReadGuard { lock: self }),
+                Err(current) => state = current,
+            }
+        }
+    }
+
+    /// Locks the lock with exclusive write access.
+    /// Blocks the current task until no other task holds the lock.
+    pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+        loop {
+            if let Some(guard) = self.try_write() {
+                return guard;
+            }
+            delay(Duration::from_millis(1));
+        }
+    }
+
+    /// Locks the lock with exclusive write access from async code.
+    ///
+    /// Unlike [`RwLock::write`], other futures get to run between attempts. However, each retry
+    /// blocks the executor's task for 1ms before yielding, which stalls every future on that
+    /// executor, so prefer [`RwLock::try_write`] in loops that need tight timing.
+    pub async fn write_async(&self) -> RwLockWriteGuard<'_, T> {
+        loop {
+            if let Some(guard) = self.try_write() {
+                return guard;
+            }
+            backoff().await;
+        }
+    }
+
+    /// Attempts to acquire this lock with exclusive write access. This function does not block.
+    ///
+    /// Returns `None` if any other task holds the lock.
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        self.state
+            .compare_exchange(0, WRITE_LOCKED, Ordering::Acquire, Ordering::Relaxed)
+            .ok()
+            .map(|_| RwLockWriteGuard { lock: self })
+    }
+
+    /// Consumes the lock and returns the inner data.
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+
+    /// Gets a mutable reference to the inner data.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T> Debug for RwLock<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct Placeholder;
+        impl Debug for Placeholder {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("<locked>")
+            }
+        }
+
+        let mut d = f.debug_struct("RwLock");
+        match self.try_read() {
+            Some(guard) => d.field("data", &&*guard),
+            None => d.field("data", &Placeholder),
+        };
+        d.finish_non_exhaustive()
+    }
+}
+
+impl<T> Default for RwLock<T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for RwLock<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Allows the user to read the data from an [`RwLock`] locked with shared read access.
+/// Dereference to get the inner data.
+#[derive(Debug)]
+pub struct RwLockReadGuard<'a, T> {
+    lock: &'a RwLock<T>,
+}
+
+impl<T> core::ops::Deref for RwLockReadGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T> Drop for RwLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.state.fetch_sub(1, Ordering::Release);
+    }
+}
+
+/// Allows the user to access the data from an [`RwLock`] locked with exclusive write access.
+/// Dereference to get the inner data.
+#[derive(Debug)]
+pub struct RwLockWriteGuard<'a, T> {
+    lock: &'a RwLock<T>,
+}
+
+impl<T> core::ops::Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T> core::ops::DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T> Drop for RwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.state.store(0, Ordering::Release);
+    }
+}
+
+/// Blocks the current task for a millisecond and then yields to the async executor once.
+///
+/// This crate can't use the async runtime's reactor (the runtime depends on it), so async
+/// waits here use this instead of waking themselves in a loop, which would keep the executor
+/// busy. Other FreeRTOS tasks run during the delay, but the delay blocks the whole executor,
+/// so other futures only run after the yield.
+fn backoff() -> impl Future<Output = ()> {
+    let mut yielded = false;
+    poll_fn(move |cx| {
+        if yielded {
+            return Poll::Ready(());
+        }
+
+        delay(Duration::from_millis(1));
+        yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    })
+}
+
+/// The [`OnceCell`] has not been initialized.
+const UNINIT: u8 = 0;
+/// A task is currently initializing the [`OnceCell`].
+const RUNNING: u8 = 1;
+/// The [`OnceCell`] holds a value.
+const COMPLETE: u8 = 2;
+
+/// A cell that can be written to only once, which can be shared between tasks.
+///
+/// This is useful for one-time initialization of shared state, such as a `static` that can only
+/// be created once the program is running. If several tasks try to initialize the cell at the
+/// same time, exactly one initializer runs and the others sleep for a millisecond between checks
+/// until the value is ready.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pros::core::sync::OnceCell;
+///
+/// static TRACK_WIDTH: OnceCell<f64> = OnceCell::new();
+///
+/// let width = TRACK_WIDTH.get_or_init(|| 12.5);
+/// assert_eq!(TRACK_WIDTH.get(), Some(width));
+/// ```
+pub struct OnceCell<T> {
+    state: AtomicU8,
+    data: UnsafeCell<MaybeUninit<T>>,
+}
+unsafe impl<T: Send> Send for OnceCell<T> {}
+unsafe impl<T: Send + Sync> Sync for OnceCell<T> {}
+
+impl<T> OnceCell<T> {
+    /// Creates a new, uninitialized cell.
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(UNINIT),
+            data: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Gets a reference to the value in the cell, or `None` if it hasn't been initialized yet.
+    ///
+    /// This does not block, even if another task is currently initializing the cell.
+    pub fn get(&self) -> Option<&T> {
+        if self.state.load(Ordering::Acquire) == COMPLETE {
+            // SAFETY: The value is never modified once the cell is complete.
+            Some(unsafe { (*self.data.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Gets a mutable reference to the value in the cell, or `None` if it hasn't been initialized yet.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if *self.state.get_mut() == COMPLETE {
+            // SAFETY: The cell is complete, so the value has been written.
+            Some(unsafe { self.data.get_mut().assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Sets the value of the cell.
+    ///
+    /// Returns the given value back as an error if the cell has already been initialized
+    /// or is being initialized by another task.
+    pub fn set(&self, value: T) -> Result<(), T> {
+        let Some(guard) = self.try_begin_init() else {
+            return Err(value);
+        };
+        guard.finish(value);
+        Ok(())
+    }
+
+    /// Gets the value in the cell, initializing it with `f` if it is empty.
+    ///
+    /// If another task is already initializing the cell, this blocks the current task until it
+    /// finishes. Calling this from inside of `f` on the same cell deadlocks.
+    pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+        let mut f = Some(f);
+        loop {
+            if let Some(value) = self.get() {
+                return value;
+            }
+
+            // An async initializer may have been dropped, so try again rather than only waiting.
+            if let Some(guard) = self.try_begin_init() {
+                guard.finish(f.take().unwrap()());
+            } else {
+                delay(Duration::from_millis(1));
+            }
+        }
+    }
+
+    /// Gets the value in the cell, initializing it with the future returned by `f` if it is empty.
+    ///
+    /// Only one initializer future runs at a time. Other callers retry until it finishes,
+    /// yielding to the executor between attempts so that other futures (including the
+    /// initializer) can make progress. Each retry blocks the executor's task for 1ms before
+    /// yielding, which stalls every future on that executor. If the initializing future is
+    /// dropped before it completes, the cell is left empty and one of the waiting callers
+    /// initializes it instead.
+    pub async fn get_or_init_async<F>(&self, f: impl FnOnce() -> F) -> &T
+    where
+        F: Future<Output = T>,
+    {
+        let mut f = Some(f);
+        loop {
+            if let Some(value) = self.get() {
+                return value;
+            }
+
+            if let Some(guard) = self.try_begin_init() {
+                guard.finish(f.take().unwrap()().await);
+            } else {
+                backoff().await;
+            }
+        }
+    }
+
+    /// Consumes the cell, returning the value inside of it if it has been initialized.
+    pub fn into_inner(mut self) -> Option<T> {
+        self.take()
+    }
+
+    /// Takes the value out of the cell, leaving it uninitialized.
+    pub fn take(&mut self) -> Option<T> {
+        if mem::replace(self.state.get_mut(), UNINIT) == COMPLETE {
+            // SAFETY: The cell was complete, and has now been marked as uninitialized so the
+            // value will not be read or dropped again.
+            Some(unsafe { self.data.get_mut().assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    /// Claims the right to initialize the cell, if nobody else has.
+    fn try_begin_init(&self) -> Option<InitGuard<'_, T>> {
+        self.state
+            .compare_exchange(UNINIT, RUNNING, Ordering::Acquire, Ordering::Acquire)
+            .ok()
+            .map(|_| InitGuard { cell: self })
+    }
+}
+
+impl<T> Drop for OnceCell<T> {
+    fn drop(&mut self) {
+        _ = self.take();
+    }
+}
+
+impl<T: Debug> Debug for OnceCell<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut d = f.debug_tuple("OnceCell");
+        match self.get() {
+            Some(value) => d.field(value),
+            None => d.field(&format_args!("<uninit>")),
+        };
+        d.finish()
+    }
+}
+
+impl<T> Default for OnceCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<T> for OnceCell<T> {
+    fn from(value: T) -> Self {
+        Self {
+            state: AtomicU8::new(COMPLETE),
+            data: UnsafeCell::new(MaybeUninit::new(value)),
+        }
+    }
+}
+
+/// Exclusive permission to initialize a [`OnceCell`].
+///
+/// If this is dropped without calling [`InitGuard::finish`], the cell is reset so that another
+/// caller can initialize it.
+struct InitGuard<'a, T> {
+    cell: &'a OnceCell<T>,
+}
+
+impl<T> InitGuard<'_, T> {
+    fn finish(self, value: T) {
+        // SAFETY: Holding the guard means that no other task can access the data.
+        unsafe { (*self.cell.data.get()).write(value) };
+        self.cell.state.store(COMPLETE, Ordering::Release);
+        mem::forget(self);
+    }
+}
+
+impl<T> Drop for InitGuard<'_, T> {
+    fn drop(&mut self) {
+        self.cell.state.store(UNINIT, Ordering::Release);
+    }
+}
+
+/// A value that is initialized the first time it is accessed.
+///
+/// This is a [`OnceCell`] paired with the function used to initialize it, which makes it
+/// convenient for `static`s that can't be created in a `const` context.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pros::core::sync::{LazyLock, Mutex};
+///
+/// static POSE: LazyLock<Mutex<(f64, f64)>> = LazyLock::new(|| Mutex::new((0.0, 0.0)));
+///
+/// POSE.lock().0 += 1.0;
+/// ```
+pub struct LazyLock<T, F = fn() -> T> {
+    cell: OnceCell<T>,
+    init: UnsafeCell<Option<F>>,
+}
+unsafe impl<T: Send + Sync, F: Send> Sync for LazyLock<T, F> {}
+
+impl<T, F: FnOnce() -> T> LazyLock<T, F> {
+    /// Creates a new lazily initialized value that will be created with `init`.
+    pub const fn new(init: F) -> Self {
+        Self {
+            cell: OnceCell::new(),
+            init: UnsafeCell::new(Some(init)),
+        }
+    }
+
+    /// Gets the value, initializing it if it hasn't been accessed before.
+    ///
+    /// This is equivalent to dereferencing the lock.
+    pub fn force(this: &Self) -> &T {
+        this.cell.get_or_init(|| {
+            // SAFETY: `get_or_init` only runs this closure once, and only in one task at a time.
+            let init = unsafe { (*this.init.get()).take() };
+            init.expect("LazyLock initializer panicked")()
+        })
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for LazyLock<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        Self::force(self)
+    }
+}
+
+impl<T: Debug, F> Debug for LazyLock<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("LazyLock").field(&self.cell).finish()
+    }
+}
@@ -22,8 +22,15 @@ pub mod local;
 use alloc::{
     boxed::Box,
     string::{String, ToString},
+    sync::Arc,
+};
+use core::{
+    ffi::CStr,
+    hash::Hash,
+    str::Utf8Error,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
 };
-use core::{ffi::CStr, hash::Hash, str::Utf8Error, time::Duration};
 
 use snafu::Snafu;
 
@@ -44,10 +51,14 @@ where
 fn spawn_inner<F: FnOnce() + Send + 'static>(
     function: F,
     priority: TaskPriority,
-    stack_depth: TaskStackDepth,
+    stack_depth: u16,
     name: Option<&str>,
 ) -> Result<TaskHandle, SpawnError> {
-    let entrypoint = Box::new(TaskEntrypoint { function });
+    let finished = Arc::new(AtomicBool::new(false));
+    let entrypoint = Box::new(TaskEntrypoint {
+        function,
+        finished: finished.clone(),
+    });
     let name = alloc::ffi::CString::new(name.unwrap_or("<unnamed>"))
         .unwrap()
         .into_raw();
@@ -58,21 +69,31 @@ fn spawn_inner<F: FnOnce() + Send + 'static>(
                 Some(TaskEntrypoint::<F>::cast_and_call_external),
                 Box::into_raw(entrypoint).cast(),
                 priority as _,
-                stack_depth as _,
+                stack_depth,
                 name,
             )
         );
 
         _ = alloc::ffi::CString::from_raw(name);
 
-        Ok(TaskHandle { task })
+        Ok(TaskHandle {
+            task,
+            finished: Some(finished),
+        })
     }
 }
 
 /// An owned permission to perform actions on a task.
+///
+/// Handles to tasks spawned with [`spawn`] or a [`Builder`] (and their clones) can tell when the
+/// task has finished. Handles obtained from [`current`] can't, since the task may not have been
+/// spawned from Rust, so [`TaskHandle::is_finished`] and [`TaskHandle::join_timeout`] return
+/// `None` for them.
 #[derive(Debug, Clone)]
 pub struct TaskHandle {
     pub(crate) task: pros_sys::task_t,
+    /// Set once the task's closure returns, if the task was spawned through this module.
+    finished: Option<Arc<AtomicBool>>,
 }
 unsafe impl Send for TaskHandle {}
 impl Hash for TaskHandle {
@@ -112,6 +133,11 @@ impl TaskHandle {
         }
     }
 
+    /// Gets the task's current priority.
+    pub fn priority(&self) -> u32 {
+        unsafe { pros_sys::task_get_priority(self.task) }
+    }
+
     /// Get the state of the task.
     pub fn state(&self) -> TaskState {
         unsafe { pros_sys::task_get_state(self.task).into() }
@@ -131,6 +157,36 @@ impl TaskHandle {
         }
     }
 
+    /// Returns `Some(true)` if the task's closure has returned.
+    ///
+    /// Returns `None` if this handle can't track the task's completion (see [`TaskHandle`]).
+    /// Panicking in a task exits the whole program, so a task that has not finished has not panicked.
+    pub fn is_finished(&self) -> Option<bool> {
+        self.finished
+            .as_ref()
+            .map(|finished| finished.load(Ordering::Acquire))
+    }
+
+    /// Waits up to `timeout` for the task to finish, checking every millisecond.
+    ///
+    /// Returns `Some(true)` if the task finished in time, or `None` without waiting if this
+    /// handle can't track the task's completion (see [`TaskHandle`]).
+    /// Timeouts longer than [`i32::MAX`] milliseconds (~24 days) are clamped.
+    /// Unlike [`join`](Self::join), this does not consume the handle, so it can be retried.
+    pub fn join_timeout(&self, timeout: Duration) -> Option<bool> {
+        let finished = self.finished.as_ref()?;
+        let start = unsafe { pros_sys::millis() };
+        let timeout = timeout.as_millis().min(i32::MAX as u128) as u32;
+
+        while !finished.load(Ordering::Acquire) {
+            if unsafe { pros_sys::millis() }.wrapping_sub(start) >= timeout {
+                return Some(false);
+            }
+            delay(Duration::from_millis(1));
+        }
+        Some(true)
+    }
+
     /// Aborts the task and consumes it. Memory allocated by the task will not be freed.
     pub fn abort(self) {
         unsafe {
@@ -153,7 +209,7 @@ impl TaskHandle {
 pub struct Builder<'a> {
     name: Option<&'a str>,
     priority: Option<TaskPriority>,
-    stack_depth: Option<TaskStackDepth>,
+    stack_depth: Option<u16>,
 }
 
 impl<'a> Builder<'a> {
@@ -177,7 +233,14 @@ impl<'a> Builder<'a> {
     /// Sets how large the stack for the task is.
     /// This can usually be set to default
     pub const fn stack_depth(mut self, stack_depth: TaskStackDepth) -> Self {
-        self.stack_depth = Some(stack_depth);
+        self.stack_depth = Some(stack_depth as u16);
+        self
+    }
+
+    /// Sets the size of the task's stack in words (4 bytes each), for when neither
+    /// [`TaskStackDepth`] preset fits.
+    pub const fn stack_size(mut self, words: u16) -> Self {
+        self.stack_depth = Some(words);
         self
     }
 
@@ -189,7 +252,7 @@ impl<'a> Builder<'a> {
         spawn_inner(
             function,
             self.priority.unwrap_or_default(),
-            self.stack_depth.unwrap_or_default(),
+            self.stack_depth.unwrap_or(TaskStackDepth::Default as u16),
             self.name,
         )
     }
@@ -263,6 +326,7 @@ pub enum TaskStackDepth {
 
 struct TaskEntrypoint<F> {
     function: F,
+    finished: Arc<AtomicBool>,
 }
 
 impl<F> TaskEntrypoint<F>
@@ -273,7 +337,8 @@ where
         // SAFETY: caller must ensure `this` is an owned `TaskEntrypoint<F>` on the heap
         let this = unsafe { Box::from_raw(this.cast::<Self>()) };
 
-        (this.function)()
+        (this.function)();
+        this.finished.store(true, Ordering::Release);
     }
 }
 
@@ -301,6 +366,12 @@ pub fn delay(duration: Duration) {
     unsafe { pros_sys::delay(duration.as_millis() as u32) }
 }
 
+/// Yields the rest of the current task's time slice, letting other ready tasks of the same
+/// priority run.
+pub fn yield_now() {
+    unsafe { pros_sys::task_delay(0) }
+}
+
 /// An interval that can be used to repeatedly run code at a given rate.
 #[derive(Debug)]
 pub struct Interval {
@@ -336,7 +407,10 @@ impl Interval {
 pub fn current() -> TaskHandle {
     unsafe {
         let task = pros_sys::task_get_current();
-        TaskHandle { task }
+        TaskHandle {
+            task,
+            finished: None,
+        }
     }
 }
 
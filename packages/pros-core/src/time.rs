@@ -6,6 +6,19 @@ use core::{
     time::Duration,
 };
 
+/// Returns the amount of time since the user program was started, with microsecond precision.
+///
+/// # Examples
+///
+/// ```
+/// use pros::time::uptime;
+///
+/// println!("Running for {:?}", uptime());
+/// ```
+pub fn uptime() -> Duration {
+    Duration::from_micros(unsafe { pros_sys::rtos::micros() })
+}
+
 /// Represents a timestamp on a monotonically nondecreasing clock relative to the
 /// start of the user program.
 ///
@@ -86,6 +86,9 @@ impl AdiAnalogIn {
     ///
     /// The [`Self::calibrate`] function must be run first on that channel.
     ///
+    /// The returned value is the difference between the current 12-bit reading and the calibrated
+    /// value, so it ranges from -4095 to 4095.
+    ///
     /// This function is inappropriate for sensor values intended for integration,
     /// as round-off error can accumulate causing drift over time.
     /// Use [`Self::high_precision_calibrated_value`] instead.
@@ -111,14 +114,14 @@ impl AdiAnalogIn {
     /// so that errors induced by the average value being
     /// between two values come out in the wash when integrated over time.
     ///
-    /// Think of the value as the true value times 16.
-    pub fn high_precision_calibrated_value(&self) -> Result<i16, AdiError> {
+    /// Think of the value as the true value times 16, which gives a range of -65520 to 65520.
+    pub fn high_precision_calibrated_value(&self) -> Result<i32, AdiError> {
         Ok(bail_on!(PROS_ERR, unsafe {
             pros_sys::ext_adi_analog_read_calibrated_HR(
                 self.port.internal_expander_index(),
                 self.port.index(),
             )
-        }) as i16)
+        }))
     }
 }
 
@@ -54,7 +54,6 @@ impl core::ops::Not for LogicLevel {
 
 /// Generic digital input ADI device.
 #[derive(Debug, Eq, PartialEq)]
-/// Generic digital input ADI device.
 pub struct AdiDigitalIn {
     port: AdiPort,
 }
@@ -85,14 +84,14 @@ impl AdiDigitalIn {
         })
     }
 
-    /// Returns `true` if the digital input's logic level level is [`LogicLevel::High`].
+    /// Returns `true` if the digital input's logic level is [`LogicLevel::High`].
     pub fn is_high(&self) -> Result<bool, AdiError> {
         Ok(self.level()?.is_high())
     }
 
-    /// Returns `true` if the digital input's logic level level is [`LogicLevel::Low`].
+    /// Returns `true` if the digital input's logic level is [`LogicLevel::Low`].
     pub fn is_low(&self) -> Result<bool, AdiError> {
-        Ok(self.level()?.is_high())
+        Ok(self.level()?.is_low())
     }
 }
 
@@ -113,13 +112,19 @@ impl AdiDevice for AdiDigitalIn {
 }
 
 /// Generic digital output ADI device.
+///
+/// ADI outputs can't be read back from the hardware, so the last logic level that was set
+/// is stored in the device.
 #[derive(Debug, Eq, PartialEq)]
 pub struct AdiDigitalOut {
     port: AdiPort,
+    level: LogicLevel,
 }
 
 impl AdiDigitalOut {
     /// Create a digital output from an [`AdiPort`].
+    ///
+    /// The output starts out at [`LogicLevel::Low`].
     pub fn new(port: AdiPort) -> Result<Self, AdiError> {
         bail_on!(PROS_ERR, unsafe {
             pros_sys::ext_adi_port_set_config(
@@ -129,7 +134,10 @@ impl AdiDigitalOut {
             )
         });
 
-        Ok(Self { port })
+        Ok(Self {
+            port,
+            level: LogicLevel::Low,
+        })
     }
 
     /// Sets the digital logic level (high or low) of a pin.
@@ -141,10 +149,33 @@ impl AdiDigitalOut {
                 level.is_high(),
             )
         });
+        self.level = level;
 
         Ok(())
     }
 
+    /// Returns the logic level that the output was last set to.
+    pub const fn level(&self) -> LogicLevel {
+        self.level
+    }
+
+    /// Returns `true` if the output was last set to [`LogicLevel::High`].
+    pub const fn is_high(&self) -> bool {
+        self.level.is_high()
+    }
+
+    /// Returns `true` if the output was last set to [`LogicLevel::Low`].
+    pub const fn is_low(&self) -> bool {
+        self.level.is_low()
+    }
+
+    /// Switches the output to the opposite of the logic level it was last set to.
+    ///
+    /// This is useful for flipping a pneumatic solenoid with a single button.
+    pub fn toggle(&mut self) -> Result<(), AdiError> {
+        self.set_level(!self.level)
+    }
+
     /// Set the digital logic level to [`LogicLevel::High`]. Analagous to
     /// [`Self::set_level(LogicLevel::High)`].
     pub fn set_high(&mut self) -> Result<(), AdiError> {
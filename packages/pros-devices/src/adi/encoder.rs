@@ -1,5 +1,7 @@
 //! ADI encoder device.
 
+use core::time::Duration;
+
 use pros_core::bail_on;
 use pros_sys::{ext_adi_encoder_t, PROS_ERR};
 
@@ -13,9 +15,32 @@ pub struct AdiEncoder {
     raw: ext_adi_encoder_t,
     port_top: AdiPort,
     port_bottom: AdiPort,
+    reversed: bool,
+    samples: [EncoderSample; Self::VELOCITY_SAMPLES],
+    sample_count: usize,
+    newest_sample: usize,
+}
+
+/// A tick count recorded at a given time, used for velocity estimation.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+struct EncoderSample {
+    ticks: i32,
+    millis: u32,
 }
 
 impl AdiEncoder {
+    /// Number of encoder ticks per full revolution of the shaft.
+    pub const TICKS_PER_REVOLUTION: u32 = 360;
+
+    /// The minimum time between velocity samples.
+    ///
+    /// This matches the 10ms update rate of ADI devices. Calling [`AdiEncoder::velocity`] more
+    /// often than this will not record additional samples.
+    pub const MIN_SAMPLE_INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Number of samples kept for velocity estimation.
+    const VELOCITY_SAMPLES: usize = 8;
+
     /// Create a new encoder from a top and bottom [`AdiPort`].
     ///
     /// If using an [`AdiExpander`], both ports must be on the same expander module.
@@ -32,7 +57,8 @@ impl AdiEncoder {
                 port_top.internal_expander_index(),
                 port_top.index(),
                 port_bottom.index(),
-                reverse,
+                // Reversing is handled by us so that it can be changed later.
+                false,
             )
         });
 
@@ -40,20 +66,96 @@ impl AdiEncoder {
             raw,
             port_top,
             port_bottom,
+            reversed: reverse,
+            samples: Default::default(),
+            sample_count: 0,
+            newest_sample: 0,
         })
     }
 
     /// Resets the encoder to zero.
     pub fn zero(&mut self) -> Result<(), AdiError> {
-        bail_on!(PROS_ERR, unsafe { pros_sys::adi_encoder_reset(self.raw) });
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_encoder_reset(self.raw)
+        });
+        self.sample_count = 0;
         Ok(())
     }
 
-    /// Gets the number of ticks recorded by the encoder.
+    /// Gets the raw number of ticks recorded by the encoder, ignoring the reverse flag.
+    fn raw_ticks(&self) -> Result<i32, AdiError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_encoder_get(self.raw)
+        }))
+    }
+
+    /// Applies the reverse flag to a tick count.
+    const fn apply_direction(&self, ticks: i32) -> i32 {
+        if self.reversed {
+            -ticks
+        } else {
+            ticks
+        }
+    }
+
+    /// Gets the angle of the encoder shaft.
+    ///
+    /// Each of the encoder's 360 ticks per revolution corresponds to one degree.
     pub fn position(&self) -> Result<Position, AdiError> {
-        let degrees = bail_on!(PROS_ERR, unsafe { pros_sys::adi_encoder_get(self.raw) });
+        let ticks = self.apply_direction(self.raw_ticks()?);
+
+        Ok(Position::from_degrees(ticks as f64))
+    }
+
+    /// Estimates the velocity of the encoder shaft in rotations per minute.
+    ///
+    /// ADI encoders don't measure velocity, so it is instead estimated from the change in
+    /// position between calls to this function. Samples are recorded at most once every
+    /// [`AdiEncoder::MIN_SAMPLE_INTERVAL`], and the estimate is averaged over the last several
+    /// samples. This function should be called regularly (such as once per loop iteration)
+    /// for the estimate to be accurate. The first call will always return `0.0`.
+    pub fn velocity(&mut self) -> Result<f64, AdiError> {
+        let sample = EncoderSample {
+            ticks: self.raw_ticks()?,
+            millis: unsafe { pros_sys::millis() },
+        };
+
+        let newest = self.samples[self.newest_sample];
+        if self.sample_count == 0
+            || Duration::from_millis(sample.millis.wrapping_sub(newest.millis) as u64)
+                >= Self::MIN_SAMPLE_INTERVAL
+        {
+            self.newest_sample = (self.newest_sample + 1) % Self::VELOCITY_SAMPLES;
+            self.samples[self.newest_sample] = sample;
+            self.sample_count = (self.sample_count + 1).min(Self::VELOCITY_SAMPLES);
+        }
+
+        let oldest_index = (self.newest_sample + Self::VELOCITY_SAMPLES + 1 - self.sample_count)
+            % Self::VELOCITY_SAMPLES;
+        let oldest = self.samples[oldest_index];
+
+        let elapsed_millis = sample.millis.wrapping_sub(oldest.millis);
+        if elapsed_millis == 0 {
+            return Ok(0.0);
+        }
+
+        let rotations = self.apply_direction(sample.ticks.wrapping_sub(oldest.ticks)) as f64
+            / Self::TICKS_PER_REVOLUTION as f64;
+        let minutes = elapsed_millis as f64 / 60_000.0;
+
+        Ok(rotations / minutes)
+    }
+
+    /// Sets whether the encoder's readings are reversed.
+    ///
+    /// Changing this flag also negates the current position.
+    pub fn set_reversed(&mut self, reversed: bool) {
+        self.reversed = reversed;
+    }
 
-        Ok(Position::from_degrees(degrees as f64))
+    /// Returns `true` if the encoder's readings are reversed.
+    pub const fn is_reversed(&self) -> bool {
+        self.reversed
     }
 }
 
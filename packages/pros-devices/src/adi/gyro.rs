@@ -8,10 +8,13 @@ use pros_sys::{ext_adi_gyro_t, PROS_ERR, PROS_ERR_F};
 use super::{AdiDevice, AdiDeviceType, AdiError, AdiPort};
 
 /// ADI gyro device.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, PartialEq)]
 pub struct AdiGyro {
-    raw: ext_adi_gyro_t,
+    /// The SDK handle for the gyro, or `None` if a failed [`AdiGyro::calibrate`] left the port
+    /// without one.
+    raw: Option<ext_adi_gyro_t>,
     port: AdiPort,
+    multiplier: f64,
 }
 
 impl AdiGyro {
@@ -30,24 +33,87 @@ impl AdiGyro {
             pros_sys::ext_adi_gyro_init(port.internal_expander_index(), port.index(), multiplier)
         });
 
-        Ok(Self { raw, port })
+        Ok(Self {
+            raw: Some(raw),
+            port,
+            multiplier,
+        })
     }
 
-    /// Gets the yaw angle of the gyroscope in degrees.
+    /// Recalibrates the gyro.
     ///
+    /// This function blocks for [`AdiGyro::CALIBRATION_TIME`] while the gyro calibrates.
+    /// The robot should be stationary for the entire calibration period.
+    ///
+    /// If reinitializing the gyro fails, reading or zeroing it returns
+    /// [`AdiError::PortNotConfigured`] until a later call to this function succeeds.
+    pub fn calibrate(&mut self) -> Result<(), AdiError> {
+        // The SDK only calibrates gyros when a port is first configured as a gyro, so the
+        // port's configuration needs to be cleared before reinitializing it.
+        if let Some(raw) = self.raw {
+            bail_on!(PROS_ERR, unsafe { pros_sys::ext_adi_gyro_shutdown(raw) });
+            self.raw = None;
+        }
+        self.raw = Some(bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_gyro_init(
+                self.port.internal_expander_index(),
+                self.port.index(),
+                self.multiplier,
+            )
+        }));
+
+        Ok(())
+    }
+
+    /// Returns the SDK handle for the gyro.
+    const fn raw(&self) -> Result<ext_adi_gyro_t, AdiError> {
+        match self.raw {
+            Some(raw) => Ok(raw),
+            None => Err(AdiError::PortNotConfigured),
+        }
+    }
+
+    /// Gets the total accumulated yaw rotation of the gyroscope in degrees.
+    ///
+    /// This value is unbounded, so turning one and a half times clockwise will return `540.0`.
     /// Unless a multiplier is applied to the gyro, the return value will be a whole
     /// number representing the number of degrees of rotation.
-    pub fn angle(&self) -> Result<f64, AdiError> {
-        Ok(bail_on!(PROS_ERR_F, unsafe { pros_sys::ext_adi_gyro_get(self.raw) }) / 10.0)
+    pub fn rotation(&self) -> Result<f64, AdiError> {
+        let raw = self.raw()?;
+        Ok(bail_on!(PROS_ERR_F, unsafe { pros_sys::ext_adi_gyro_get(raw) }) / 10.0)
+    }
+
+    /// Gets the yaw heading of the gyroscope in degrees, bounded to [0, 360).
+    pub fn heading(&self) -> Result<f64, AdiError> {
+        let heading = self.rotation()? % 360.0;
+        if heading >= 0.0 {
+            return Ok(heading);
+        }
+
+        // Tiny negative rotations round up to exactly 360.0, which is outside the range.
+        let heading = heading + 360.0;
+        Ok(if heading >= 360.0 { 0.0 } else { heading })
     }
 
     /// Reset the current gyro angle to zero degrees.
     pub fn zero(&mut self) -> Result<(), AdiError> {
-        bail_on!(PROS_ERR, unsafe { pros_sys::ext_adi_gyro_reset(self.raw) });
+        let raw = self.raw()?;
+        bail_on!(PROS_ERR, unsafe { pros_sys::ext_adi_gyro_reset(raw) });
         Ok(())
     }
 }
 
+impl Drop for AdiGyro {
+    fn drop(&mut self) {
+        // Free the port so that it can be reused by another device.
+        if let Some(raw) = self.raw {
+            unsafe {
+                pros_sys::ext_adi_gyro_shutdown(raw);
+            }
+        }
+    }
+}
+
 impl AdiDevice for AdiGyro {
     type PortIndexOutput = u8;
 
@@ -0,0 +1,69 @@
+//! ADI Light Sensor
+//!
+//! Light sensors measure the intensity of ambient light hitting the sensor.
+//!
+//! # Hardware
+//!
+//! The Light Sensor is an analog sensor, and it internally measures values in the
+//! range of 0 to 4095 from 0-5V. Brighter light is indicated by lower numbers, and
+//! darker conditions are indicated by higher numbers.
+
+use pros_core::bail_on;
+use pros_sys::PROS_ERR;
+
+use super::{AdiDevice, AdiDeviceType, AdiError, AdiPort};
+
+/// Analog light sensor device.
+#[derive(Debug, Eq, PartialEq)]
+pub struct AdiLightSensor {
+    port: AdiPort,
+}
+
+impl AdiLightSensor {
+    /// Create a light sensor on an ADI port.
+    pub fn new(port: AdiPort) -> Result<Self, AdiError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_port_set_config(
+                port.internal_expander_index(),
+                port.index(),
+                pros_sys::E_ADI_ANALOG_IN,
+            )
+        });
+
+        Ok(Self { port })
+    }
+
+    /// Get the brightness factor measured by the sensor.
+    ///
+    /// This is returned as a value ranging from [0.0, 1.0], where higher values
+    /// indicate brighter light.
+    pub fn brightness(&self) -> Result<f64, AdiError> {
+        Ok(1.0 - self.raw_brightness()? as f64 / 4095.0)
+    }
+
+    /// Get the raw brightness factor of the sensor.
+    ///
+    /// This is a raw 12-bit value from [0, 4095] representing the voltage level from
+    /// 0-5V measured by the V5 brain's ADC. Lower values indicate brighter light.
+    pub fn raw_brightness(&self) -> Result<u16, AdiError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_analog_read(self.port.internal_expander_index(), self.port.index())
+        }) as u16)
+    }
+}
+
+impl AdiDevice for AdiLightSensor {
+    type PortIndexOutput = u8;
+
+    fn port_index(&self) -> Self::PortIndexOutput {
+        self.port.index()
+    }
+
+    fn expander_port_index(&self) -> Option<u8> {
+        self.port.expander_index()
+    }
+
+    fn device_type(&self) -> AdiDeviceType {
+        AdiDeviceType::AnalogIn
+    }
+}
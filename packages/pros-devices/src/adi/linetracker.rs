@@ -48,14 +48,38 @@ impl AdiLineTracker {
         Ok(Self { port })
     }
 
+    /// Calibrates the line tracker.
+    ///
+    /// This computes an average of the sensor's readings over a period of 0.5 seconds, during
+    /// which the reading should not be changing. The calibrated value is not used by
+    /// [`AdiLineTracker::reflectivity`], which always returns an absolute reading.
+    pub fn calibrate(&mut self) -> Result<(), AdiError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_analog_calibrate(
+                self.port.internal_expander_index(),
+                self.port.index(),
+            )
+        });
+
+        Ok(())
+    }
+
     /// Get the reflectivity factor measured by the sensor.
     ///
-    /// This is returned as a value ranging from [0.0, 1.0].
+    /// This is returned as a value ranging from [0.0, 1.0], where higher values
+    /// indicate a lighter (more reflective) surface.
     pub fn reflectivity(&self) -> Result<f64, AdiError> {
-        Ok(bail_on!(PROS_ERR, unsafe {
-            pros_sys::ext_adi_analog_read(self.port.internal_expander_index(), self.port.index())
-        }) as f64
-            / 4095.0)
+        Ok(1.0 - self.raw_reflectivity()? as f64 / 4095.0)
+    }
+
+    /// Returns `true` if the sensor is over a dark line on a light surface.
+    ///
+    /// This is the case when the measured [reflectivity](AdiLineTracker::reflectivity)
+    /// is below `threshold`, which should be between 0.0 and 1.0. The best threshold
+    /// depends on the field's surface and lighting, and is typically found by comparing
+    /// readings taken over the line and over the background.
+    pub fn on_line(&self, threshold: f64) -> Result<bool, AdiError> {
+        Ok(self.reflectivity()? < threshold)
     }
 
     /// Get the raw reflectivity factor of the sensor.
@@ -11,9 +11,11 @@ pub mod pwm;
 
 pub mod encoder;
 pub mod gyro;
+pub mod light_sensor;
 pub mod linetracker;
 pub mod motor;
 pub mod potentiometer;
+pub mod servo;
 pub mod solenoid;
 pub mod switch;
 pub mod ultrasonic;
@@ -22,9 +24,11 @@ pub use analog::AdiAnalogIn;
 pub use digital::{AdiDigitalIn, AdiDigitalOut};
 pub use encoder::AdiEncoder;
 pub use gyro::AdiGyro;
+pub use light_sensor::AdiLightSensor;
 pub use linetracker::AdiLineTracker;
 pub use motor::AdiMotor;
 pub use potentiometer::AdiPotentiometer;
+pub use servo::AdiServo;
 pub use solenoid::AdiSolenoid;
 pub use ultrasonic::AdiUltrasonic;
 
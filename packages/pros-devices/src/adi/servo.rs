@@ -0,0 +1,87 @@
+//! ADI servo device.
+//!
+//! # Hardware
+//!
+//! Cortex-era three-wire servos rotate to a commanded position within a limited range of
+//! travel, rather than spinning continuously like a motor. The servo is commanded using
+//! a PWM signal, which is represented as a raw value from [-127, 127]. This spans roughly
+//! 100 degrees of travel, from -50 degrees to +50 degrees about the servo's center.
+
+use pros_core::bail_on;
+use pros_sys::PROS_ERR;
+
+use super::{AdiDevice, AdiDeviceType, AdiError, AdiPort};
+
+/// Cortex era servo device.
+#[derive(Debug, Eq, PartialEq)]
+pub struct AdiServo {
+    port: AdiPort,
+}
+
+impl AdiServo {
+    /// The maximum angle that the servo can be commanded to in either direction from its center, in degrees.
+    pub const MAX_ANGLE: f64 = 50.0;
+
+    /// Create a new servo from an [`AdiPort`].
+    pub fn new(port: AdiPort) -> Result<Self, AdiError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_port_set_config(
+                port.internal_expander_index(),
+                port.index(),
+                pros_sys::E_ADI_LEGACY_SERVO,
+            )
+        });
+
+        Ok(Self { port })
+    }
+
+    /// Sets the target position of the servo as a raw value from [-127, 127].
+    pub fn set_position(&mut self, value: i8) -> Result<(), AdiError> {
+        bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_port_set_value(
+                self.port.internal_expander_index(),
+                self.port.index(),
+                value as i32,
+            )
+        });
+
+        Ok(())
+    }
+
+    /// Sets the target angle of the servo in degrees relative to its center.
+    ///
+    /// Angles outside of [-[`AdiServo::MAX_ANGLE`], [`AdiServo::MAX_ANGLE`]] are clamped to the
+    /// servo's range of travel.
+    pub fn set_angle(&mut self, degrees: f64) -> Result<(), AdiError> {
+        let degrees = degrees.clamp(-Self::MAX_ANGLE, Self::MAX_ANGLE);
+        self.set_position((degrees / Self::MAX_ANGLE * 127.0) as i8)
+    }
+
+    /// Returns the last commanded position of the servo as a raw value from [-127, 127].
+    pub fn position(&self) -> Result<i8, AdiError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::ext_adi_port_get_value(self.port.internal_expander_index(), self.port.index())
+        }) as i8)
+    }
+
+    /// Returns the last commanded angle of the servo in degrees relative to its center.
+    pub fn angle(&self) -> Result<f64, AdiError> {
+        Ok(self.position()? as f64 / 127.0 * Self::MAX_ANGLE)
+    }
+}
+
+impl AdiDevice for AdiServo {
+    type PortIndexOutput = u8;
+
+    fn port_index(&self) -> Self::PortIndexOutput {
+        self.port.index()
+    }
+
+    fn expander_port_index(&self) -> Option<u8> {
+        self.port.expander_index()
+    }
+
+    fn device_type(&self) -> AdiDeviceType {
+        AdiDeviceType::LegacyServo
+    }
+}
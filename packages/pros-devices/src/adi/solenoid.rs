@@ -1,63 +1,45 @@
 //! ADI Solenoid Pneumatic Control
 
-use pros_core::bail_on;
-use pros_sys::PROS_ERR;
-
-use super::{digital::LogicLevel, AdiDevice, AdiDeviceType, AdiError, AdiPort};
+use super::{
+    digital::{AdiDigitalOut, LogicLevel},
+    AdiDevice, AdiDeviceType, AdiError, AdiPort,
+};
 
 /// Digital pneumatic solenoid valve.
+///
+/// This is a thin wrapper over an [`AdiDigitalOut`] with methods named after what the valve does.
 #[derive(Debug, Eq, PartialEq)]
 pub struct AdiSolenoid {
-    port: AdiPort,
-    level: LogicLevel,
+    output: AdiDigitalOut,
 }
 
 impl AdiSolenoid {
     /// Create an AdiSolenoid.
     pub fn new(port: AdiPort) -> Result<Self, AdiError> {
-        bail_on!(PROS_ERR, unsafe {
-            pros_sys::ext_adi_port_set_config(
-                port.internal_expander_index(),
-                port.index(),
-                pros_sys::E_ADI_DIGITAL_OUT,
-            )
-        });
-
         Ok(Self {
-            port,
-            level: LogicLevel::Low,
+            output: AdiDigitalOut::new(port)?,
         })
     }
 
     /// Sets the digital logic level of the solenoid. [`LogicLevel::Low`] will close the solenoid,
     /// and [`LogicLevel::High`] will open it.
     pub fn set_level(&mut self, level: LogicLevel) -> Result<(), AdiError> {
-        self.level = level;
-
-        bail_on!(PROS_ERR, unsafe {
-            pros_sys::ext_adi_digital_write(
-                self.port.internal_expander_index(),
-                self.port.index(),
-                level.is_high(),
-            )
-        });
-
-        Ok(())
+        self.output.set_level(level)
     }
 
     /// Returns the current [`LogicLevel`] of the solenoid's digital output state.
     pub const fn level(&self) -> LogicLevel {
-        self.level
+        self.output.level()
     }
 
     /// Returns `true` if the solenoid is open.
-    pub const fn is_open(&self) -> LogicLevel {
-        self.level
+    pub const fn is_open(&self) -> bool {
+        self.output.is_high()
     }
 
     /// Returns `true` if the solenoid is closed.
-    pub const fn is_closed(&self) -> LogicLevel {
-        self.level
+    pub const fn is_closed(&self) -> bool {
+        self.output.is_low()
     }
 
     /// Open the solenoid, allowing air pressure through the "open" valve.
@@ -77,7 +59,13 @@ impl AdiSolenoid {
 
     /// Toggle the solenoid's state between open and closed.
     pub fn toggle(&mut self) -> Result<(), AdiError> {
-        self.set_level(!self.level)
+        self.output.toggle()
+    }
+}
+
+impl From<AdiDigitalOut> for AdiSolenoid {
+    fn from(output: AdiDigitalOut) -> Self {
+        Self { output }
     }
 }
 
@@ -85,11 +73,11 @@ impl AdiDevice for AdiSolenoid {
     type PortIndexOutput = u8;
 
     fn port_index(&self) -> Self::PortIndexOutput {
-        self.port.index()
+        self.output.port_index()
     }
 
     fn expander_port_index(&self) -> Option<u8> {
-        self.port.expander_index()
+        self.output.expander_port_index()
     }
 
     fn device_type(&self) -> AdiDeviceType {
@@ -1,5 +1,8 @@
 //! ADI ultrasonic sensor.
 
+#[cfg(feature = "async")]
+use core::time::Duration;
+
 use pros_core::bail_on;
 use pros_sys::{ext_adi_ultrasonic_t, PROS_ERR};
 
@@ -41,11 +44,59 @@ impl AdiUltrasonic {
 
     /// Get the distance reading of the ultrasonic sensor in centimeters.
     ///
+    /// Returns `None` if the sensor does not detect an object in range.
+    ///
     /// Round and/or fluffy objects can cause inaccurate values to be returned.
-    pub fn distance(&self) -> Result<u16, AdiError> {
-        Ok(bail_on!(PROS_ERR, unsafe {
+    pub fn distance(&self) -> Result<Option<u32>, AdiError> {
+        let distance = bail_on!(PROS_ERR, unsafe {
             pros_sys::ext_adi_ultrasonic_get(self.raw)
-        }) as u16)
+        });
+
+        Ok(match distance {
+            // The sensor reports a distance of zero when no echo is received.
+            0 => None,
+            distance => Some(distance as u32),
+        })
+    }
+
+    /// Get the distance reading of the ultrasonic sensor in millimeters.
+    ///
+    /// This is the same reading as [`distance`](Self::distance), converted to the units used by
+    /// [`DistanceSensor::distance`](crate::smart::distance::DistanceSensor::distance) so that code
+    /// can switch between the two sensors without changing its math.
+    pub fn distance_mm(&self) -> Result<Option<u32>, AdiError> {
+        Ok(self.distance()?.map(|cm| cm * 10))
+    }
+
+    /// Waits for the sensor to detect an object, returning its distance in centimeters.
+    ///
+    /// The sensor is checked every 10ms (the ADI update rate) by sleeping with the async
+    /// runtime's reactor, so other futures keep running while waiting.
+    /// Returns `None` if no object has been detected within `timeout`.
+    #[cfg(feature = "async")]
+    pub async fn wait_for_reading(&self, timeout: Duration) -> Result<Option<u32>, AdiError> {
+        let wait = async {
+            loop {
+                if let Some(distance) = self.distance()? {
+                    return Ok(distance);
+                }
+                pros_async::sleep(Duration::from_millis(10)).await;
+            }
+        };
+
+        match pros_async::timeout(timeout, wait).await {
+            Ok(result) => result.map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+impl Drop for AdiUltrasonic {
+    fn drop(&mut self) {
+        // Free the ports so that they can be reused by another device.
+        unsafe {
+            pros_sys::ext_adi_ultrasonic_shutdown(self.raw);
+        }
     }
 }
 
@@ -4,34 +4,59 @@ use pros_core::{bail_on, map_errno};
 use pros_sys::{PROS_ERR, PROS_ERR_F};
 use snafu::Snafu;
 
-/// Get the robot's battery capacity.
+/// Get the robot's remaining battery capacity as a percentage (0-100).
 pub fn capacity() -> Result<f64, BatteryError> {
     Ok(bail_on!(PROS_ERR_F, unsafe {
         pros_sys::misc::battery_get_capacity()
     }))
 }
 
-/// Get the current temperature of the robot's battery.
+/// Get the current temperature of the robot's battery in degrees Celsius.
 pub fn temperature() -> Result<f64, BatteryError> {
     Ok(bail_on!(PROS_ERR_F, unsafe {
         pros_sys::misc::battery_get_temperature()
     }))
 }
 
-/// Get the electric current of the robot's battery.
+/// Get the electric current of the robot's battery in milliamps.
 pub fn current() -> Result<i32, BatteryError> {
     Ok(bail_on!(PROS_ERR, unsafe {
         pros_sys::misc::battery_get_current()
     }))
 }
 
-/// Get the robot's battery voltage.
+/// Get the robot's battery voltage in millivolts.
 pub fn voltage() -> Result<i32, BatteryError> {
     Ok(bail_on!(PROS_ERR, unsafe {
         pros_sys::misc::battery_get_voltage()
     }))
 }
 
+/// A snapshot of the state of the robot's battery.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatteryStatus {
+    /// The remaining capacity as a percentage (0-100).
+    pub capacity: f64,
+    /// The voltage in volts.
+    pub voltage: f64,
+    /// The electric current in amps.
+    pub current: f64,
+    /// The temperature in degrees Celsius.
+    pub temperature: f64,
+}
+
+/// Get the capacity, voltage, current and temperature of the robot's battery at once.
+///
+/// Unlike [`voltage`] and [`current`], the voltage and current are given in volts and amps.
+pub fn status() -> Result<BatteryStatus, BatteryError> {
+    Ok(BatteryStatus {
+        capacity: capacity()?,
+        voltage: voltage()? as f64 / 1000.0,
+        current: current()? as f64 / 1000.0,
+        temperature: temperature()?,
+    })
+}
+
 #[derive(Debug, Snafu)]
 /// Errors that can occur when interacting with the robot's battery.
 pub enum BatteryError {
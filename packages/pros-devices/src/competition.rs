@@ -1,5 +1,8 @@
 //! Utilities for getting what state of the competition the robot is in.
 
+use core::time::Duration;
+
+use pros_core::task::{self, delay, TaskHandle};
 use pros_sys::misc::{COMPETITION_AUTONOMOUS, COMPETITION_CONNECTED, COMPETITION_DISABLED};
 
 // TODO: change this to use PROS' internal version once we switch to PROS 4.
@@ -48,10 +51,30 @@ pub enum CompetitionSystem {
     CompetitionSwitch,
 }
 
-/// Gets the current competition mode, or phase.
-pub fn mode() -> CompetitionMode {
-    let status = unsafe { pros_sys::misc::competition_get_status() };
+/// A snapshot of the robot's competition state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompetitionStatus {
+    /// The current competition mode.
+    pub mode: CompetitionMode,
+    /// The system controlling the competition state, or [`None`] if the robot is not connected to one.
+    pub system: Option<CompetitionSystem>,
+}
+
+impl CompetitionStatus {
+    /// Returns `true` if the robot is connected to a competition control system.
+    pub const fn connected(&self) -> bool {
+        self.system.is_some()
+    }
+}
+
+/// Reads the raw competition status bits from PROS.
+///
+/// Most code should use [`status`] or one of the other functions in this module instead.
+pub fn raw_status() -> u8 {
+    unsafe { pros_sys::misc::competition_get_status() }
+}
 
+const fn mode_from_raw(status: u8) -> CompetitionMode {
     if status & COMPETITION_DISABLED != 0 {
         CompetitionMode::Disabled
     } else if status & COMPETITION_AUTONOMOUS != 0 {
@@ -61,25 +84,164 @@ pub fn mode() -> CompetitionMode {
     }
 }
 
+const fn system_from_raw(status: u8) -> Option<CompetitionSystem> {
+    if status & COMPETITION_CONNECTED != 0 {
+        if status & COMPETITION_SYSTEM == 0 {
+            Some(CompetitionSystem::FieldControl)
+        } else {
+            Some(CompetitionSystem::CompetitionSwitch)
+        }
+    } else {
+        None
+    }
+}
+
+/// Gets the current competition state.
+///
+/// The mode and system are decoded from a single read of the status bits, so they always agree with each other.
+pub fn status() -> CompetitionStatus {
+    let status = raw_status();
+
+    CompetitionStatus {
+        mode: mode_from_raw(status),
+        system: system_from_raw(status),
+    }
+}
+
+/// Gets the current competition mode, or phase.
+pub fn mode() -> CompetitionMode {
+    mode_from_raw(raw_status())
+}
+
 /// Checks if the robot is connected to a competition control system.
 pub fn connected() -> bool {
-    let status = unsafe { pros_sys::misc::competition_get_status() };
-
-    status & COMPETITION_CONNECTED != 0
+    raw_status() & COMPETITION_CONNECTED != 0
 }
 
 /// Gets the type of system currently controlling the robot's competition state, or [`None`] if the robot
 /// is not tethered to a competition controller.
 pub fn system() -> Option<CompetitionSystem> {
-    let status = unsafe { pros_sys::misc::competition_get_status() };
+    system_from_raw(raw_status())
+}
 
-    if status & COMPETITION_CONNECTED != 0 {
-        if status & COMPETITION_SYSTEM == 0 {
-            Some(CompetitionSystem::FieldControl)
-        } else {
-            Some(CompetitionSystem::CompetitionSwitch)
+/// How often [`on_change`] and [`CompetitionUpdates`] check the competition state.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Returns a watcher that can be used to wait for changes in the competition state.
+///
+/// Changes are tracked from the state at the time this function is called.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pros::devices::competition;
+///
+/// async fn watch() {
+///     let mut updates = competition::updates();
+///     loop {
+///         let status = updates.changed().await;
+///         if !status.connected() {
+///             println!("Disconnected from competition control!");
+///         }
+///     }
+/// }
+/// ```
+pub fn updates() -> CompetitionUpdates {
+    CompetitionUpdates { last: status() }
+}
+
+/// Watches for changes in the competition state.
+///
+/// Created with [`updates`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompetitionUpdates {
+    last: CompetitionStatus,
+}
+
+impl CompetitionUpdates {
+    /// Returns the last competition state that was seen by this watcher.
+    pub const fn last(&self) -> CompetitionStatus {
+        self.last
+    }
+
+    /// Waits for the competition state to differ from [`CompetitionUpdates::last`], returning the new state.
+    ///
+    /// The state is checked every 20ms, sleeping with the async runtime's reactor in between.
+    /// Reading the same state more than once does not produce another change, so every
+    /// completed future is a real transition, such as the robot being disconnected or
+    /// moving from disabled to opcontrol.
+    #[cfg(feature = "async")]
+    pub async fn changed(&mut self) -> CompetitionStatus {
+        loop {
+            let current = status();
+            if current != self.last {
+                self.last = current;
+                return current;
+            }
+            pros_async::sleep(POLL_INTERVAL).await;
         }
-    } else {
-        None
     }
+
+    /// Waits for the competition mode to change, returning the new mode.
+    ///
+    /// Unlike [`CompetitionUpdates::changed`], changes that only affect the connection to
+    /// competition control (such as switching from a field controller to a competition switch)
+    /// are skipped.
+    #[cfg(feature = "async")]
+    pub async fn mode_changed(&mut self) -> CompetitionMode {
+        let mode = self.last.mode;
+        loop {
+            let status = self.changed().await;
+            if status.mode != mode {
+                return status.mode;
+            }
+        }
+    }
+}
+
+/// Waits for the competition mode to change from the current mode, returning the new mode.
+///
+/// Each call only tracks changes from the moment it is called, so code that waits for several
+/// transitions in a row should reuse one [`CompetitionUpdates`] with [`CompetitionUpdates::mode_changed`]
+/// to avoid missing a transition between calls.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pros::devices::competition;
+///
+/// async fn log_next_mode() {
+///     let mode = competition::wait_for_mode_change().await;
+///     println!("Switched to {mode:?}");
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn wait_for_mode_change() -> CompetitionMode {
+    updates().mode_changed().await
+}
+
+/// Calls `callback` with the new competition state every time it changes.
+///
+/// The state is checked on a new FreeRTOS task every 20ms. The callback runs on that task,
+/// so it must not block for long. Dropping the returned handle does not stop the task;
+/// use [`TaskHandle::abort`] to stop receiving changes.
+///
+/// # Panics
+///
+/// Panics if the task can't be created.
+pub fn on_change(mut callback: impl FnMut(CompetitionStatus) + Send + 'static) -> TaskHandle {
+    task::Builder::new()
+        .name("competition on_change")
+        .spawn(move || {
+            let mut updates = updates();
+            loop {
+                let current = status();
+                if current != updates.last {
+                    updates.last = current;
+                    callback(current);
+                }
+                delay(POLL_INTERVAL);
+            }
+        })
+        .expect("Failed to spawn competition on_change task")
 }
@@ -4,8 +4,12 @@
 //! State of a controller can be checked by calling [`Controller::state`] which will return a struct with all of the buttons' and joysticks' state.
 
 use alloc::{ffi::CString, vec::Vec};
+use core::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
 
-use pros_core::{bail_on, map_errno};
+use pros_core::{bail_on, map_errno, task::delay};
 use pros_sys::{controller_id_e_t, PROS_ERR};
 use snafu::Snafu;
 
@@ -42,6 +46,11 @@ pub struct Buttons {
 /// Stores how far the joystick is away from the center (at *(0, 0)*) from -1 to 1.
 /// On the x axis left is negative, and right is positive.
 /// On the y axis down is negative, and up is positive.
+///
+/// # Competition Control
+///
+/// While the robot is connected to competition control, PROS reports joysticks as
+/// centered (and buttons as released) outside of the driver control period.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct Joystick {
     /// Left and right x value of the joystick
@@ -50,6 +59,95 @@ pub struct Joystick {
     pub y: f32,
 }
 
+impl Joystick {
+    /// Returns `true` if the joystick is pushed further than `threshold` along either axis.
+    ///
+    /// This is useful for treating a joystick as a button.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ControllerError::InvalidThreshold`] if `threshold` is not between 0.0 and 1.0.
+    pub fn is_deflected(&self, threshold: f32) -> Result<bool, ControllerError> {
+        if !(0.0..=1.0).contains(&threshold) {
+            return Err(ControllerError::InvalidThreshold { threshold });
+        }
+
+        Ok(!(-threshold..=threshold).contains(&self.x)
+            || !(-threshold..=threshold).contains(&self.y))
+    }
+
+    /// Returns the joystick with a deadzone applied to each axis.
+    ///
+    /// Values within `deadzone` of the center become exactly 0.0, and the remaining range is
+    /// rescaled to start at 0.0 so that a full deflection of 1.0 still maps to 1.0.
+    /// `deadzone` is clamped to the range [0.0, 0.99], and a non-finite `deadzone` is treated
+    /// as 0.0 (no deadzone).
+    ///
+    /// Raw joystick values jitter slightly around the center, so a small deadzone
+    /// (around 0.05) keeps a released joystick from creeping the robot.
+    pub fn with_deadzone(self, deadzone: f32) -> Self {
+        let deadzone = if deadzone.is_finite() {
+            deadzone.clamp(0.0, 0.99)
+        } else {
+            0.0
+        };
+        Self {
+            x: apply_deadzone(self.x, deadzone),
+            y: apply_deadzone(self.y, deadzone),
+        }
+    }
+
+    /// Applies a response curve to the joystick's x axis, returning the shaped value.
+    ///
+    /// See [`Joystick::curved_y`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ControllerError::InvalidExponent`] if `exponent` is not a positive, finite number.
+    pub fn curved_x(&self, exponent: f32) -> Result<f32, ControllerError> {
+        curve_axis(self.x, exponent)
+    }
+
+    /// Applies a response curve to the joystick's y axis, returning the shaped value.
+    ///
+    /// The magnitude of the axis is raised to the power of `exponent` while keeping its sign,
+    /// so 0.0 stays 0.0 and a full deflection of 1.0 still maps to 1.0. An exponent above 1.0
+    /// gives finer control near the center. Apply a deadzone first with [`Joystick::with_deadzone`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ControllerError::InvalidExponent`] if `exponent` is not a positive, finite number.
+    pub fn curved_y(&self, exponent: f32) -> Result<f32, ControllerError> {
+        curve_axis(self.y, exponent)
+    }
+}
+
+/// Zeroes an axis value within `deadzone` of the center and rescales the rest to [0.0, 1.0].
+fn apply_deadzone(value: f32, deadzone: f32) -> f32 {
+    let magnitude = if value < 0.0 { -value } else { value };
+    if magnitude <= deadzone {
+        return 0.0;
+    }
+
+    let rescaled = ((magnitude - deadzone) / (1.0 - deadzone)).min(1.0);
+    if value < 0.0 {
+        -rescaled
+    } else {
+        rescaled
+    }
+}
+
+/// Raises the magnitude of an axis value to the power of `exponent`, keeping its sign.
+fn curve_axis(value: f32, exponent: f32) -> Result<f32, ControllerError> {
+    if !(exponent.is_finite() && exponent > 0.0) {
+        return Err(ControllerError::InvalidExponent { exponent });
+    }
+
+    let magnitude = if value < 0.0 { -value } else { value };
+    let curved = libm::powf(magnitude.min(1.0), exponent);
+    Ok(if value < 0.0 { -curved } else { curved })
+}
+
 /// Stores both joysticks on the controller.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Joysticks {
@@ -59,13 +157,19 @@ pub struct Joysticks {
     pub right: Joystick,
 }
 
-/// Stores the current state of the controller; the joysticks and buttons.
+/// Stores the current state of the controller; the joysticks, buttons, connection, and battery.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ControllerState {
     /// Analog joysticks state
     pub joysticks: Joysticks,
     /// Digital buttons state
     pub buttons: Buttons,
+    /// Whether or not the controller is connected to the brain.
+    pub connected: bool,
+    /// The battery level of the controller.
+    pub battery_level: i32,
+    /// The battery capacity of the controller.
+    pub battery_capacity: i32,
 }
 
 /// Represents one line on the controller console.
@@ -80,34 +184,75 @@ impl ControllerLine {
     pub const MAX_TEXT_LEN: usize = 14;
     /// The maximum line number that can be used on the controller display.
     pub const MAX_LINE_NUM: u8 = 2;
+    /// The minimum amount of time between writes to a controller's display.
+    ///
+    /// The controller only accepts a new display update about every 50ms and silently
+    /// drops any that arrive sooner, so writes made within this interval of the last
+    /// successful write to the same controller return [`ControllerError::WriteThrottled`].
+    pub const MIN_WRITE_INTERVAL: Duration = Duration::from_millis(50);
 
     /// Attempts to print text to the controller display.
     /// Returns an error if the text is too long to fit on the display or if an internal PROS error occured.
     pub fn try_print(&self, text: impl Into<Vec<u8>>) -> Result<(), ControllerError> {
+        self.try_print_at(0, text)
+    }
+
+    /// Attempts to print text to the controller display, starting at the given column.
+    /// Returns an error if the text does not fit on the line starting at `column`
+    /// or if an internal PROS error occured.
+    pub fn try_print_at(
+        &self,
+        column: u8,
+        text: impl Into<Vec<u8>>,
+    ) -> Result<(), ControllerError> {
         let text = text.into();
         let text_len = text.len();
-        assert!(
-            text_len > ControllerLine::MAX_TEXT_LEN,
-            "Printed text is too long to fit on controller display ({text_len} > {})",
-            Self::MAX_TEXT_LEN
-        );
+        if text_len + column as usize > Self::MAX_TEXT_LEN {
+            return Err(ControllerError::TextTooLong {
+                len: text_len,
+                column,
+            });
+        }
         let c_text = CString::new(text).expect("parameter `text` should not contain null bytes");
-        bail_on!(PROS_ERR, unsafe {
-            pros_sys::controller_set_text(self.controller.id(), self.line, 0, c_text.as_ptr())
-        });
-        Ok(())
+        self.controller.throttle_display_write(|| unsafe {
+            pros_sys::controller_set_text(self.controller.id(), self.line, column, c_text.as_ptr())
+        })
+    }
+
+    /// Attempts to clear the line on the controller display.
+    /// Returns an error if the display was written to too recently or if an internal PROS error occured.
+    pub fn try_clear(&self) -> Result<(), ControllerError> {
+        self.controller.throttle_display_write(|| unsafe {
+            pros_sys::controller_clear_line(self.controller.id(), self.line)
+        })
     }
     /// Prints text to the controller display.
+    ///
+    /// If the display was written to less than [`ControllerLine::MIN_WRITE_INTERVAL`] ago,
+    /// this blocks the current task until the display can be written to again, so the text
+    /// is always printed. Use [`ControllerLine::try_print`] to avoid waiting.
+    ///
     /// # Panics
+    ///
     /// Unlike [`ControllerLine::try_print`],
     /// this function will panic if the text is too long to fit on the display
     /// or if an internal PROS error occured.
     pub fn print(&self, text: impl Into<Vec<u8>>) {
-        self.try_print(text).unwrap();
+        let text = text.into();
+        loop {
+            match self.try_print(text.clone()) {
+                Ok(()) => return,
+                Err(ControllerError::WriteThrottled) => delay(Duration::from_millis(1)),
+                Err(err) => panic!("{err}"),
+            }
+        }
     }
 }
 
 /// A digital channel (button) on the VEX controller.
+///
+/// This is the only place where buttons are mapped to their SDK channels. Since each variant
+/// is assigned its channel as a discriminant, the compiler rejects any two buttons sharing a channel.
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ControllerButton {
@@ -163,155 +308,161 @@ pub enum Controller {
     Partner = pros_sys::E_CONTROLLER_PARTNER,
 }
 
+/// The earliest time (in milliseconds since program start) that each controller's display
+/// can be written to again. Indexed by controller id.
+static NEXT_DISPLAY_WRITE: [AtomicU32; 2] = [AtomicU32::new(0), AtomicU32::new(0)];
+
+/// Returns `true` if `pattern` is short enough and only uses the characters the controller
+/// understands, as described in [`Controller::rumble`].
+fn is_valid_rumble_pattern(pattern: &str) -> bool {
+    pattern.len() <= Controller::MAX_RUMBLE_LENGTH
+        && pattern.chars().all(|c| matches!(c, '.' | '-' | ' '))
+}
+
 impl Controller {
+    /// The maximum number of characters in a rumble pattern passed to [`Controller::rumble`].
+    pub const MAX_RUMBLE_LENGTH: usize = 8;
+
     const fn id(&self) -> controller_id_e_t {
         *self as controller_id_e_t
     }
 
+    /// Runs a display write unless one was made within [`ControllerLine::MIN_WRITE_INTERVAL`],
+    /// in which case [`ControllerError::WriteThrottled`] is returned without calling `write`.
+    fn throttle_display_write(&self, write: impl FnOnce() -> i32) -> Result<(), ControllerError> {
+        let next_write = &NEXT_DISPLAY_WRITE[self.id() as usize];
+        let now = unsafe { pros_sys::millis() };
+        let allowed_at = next_write.load(Ordering::Acquire);
+        // Compare using wrapping arithmetic so that this keeps working when `millis` overflows.
+        if (now.wrapping_sub(allowed_at) as i32) < 0 {
+            return Err(ControllerError::WriteThrottled);
+        }
+
+        // Claim the write before making it, so that another task can't pass the check above
+        // at the same time and send a second update that the controller would drop.
+        let reserved = now.wrapping_add(ControllerLine::MIN_WRITE_INTERVAL.as_millis() as u32);
+        next_write
+            .compare_exchange(allowed_at, reserved, Ordering::AcqRel, Ordering::Acquire)
+            .map_err(|_| ControllerError::WriteThrottled)?;
+
+        let result = (|| {
+            bail_on!(PROS_ERR, write());
+            Ok(())
+        })();
+        if result.is_err() {
+            // Nothing was sent, so give the claim back unless another write has happened since.
+            _ = next_write.compare_exchange(
+                reserved,
+                allowed_at,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            );
+        }
+        result
+    }
+
     /// Returns a line on the controller display that can be used to print to the controller.
-    pub fn line(&self, line_num: u8) -> ControllerLine {
-        assert!(
-            line_num > ControllerLine::MAX_LINE_NUM,
-            "Line number is too large for controller display ({line_num} > {})",
-            ControllerLine::MAX_LINE_NUM
-        );
+    ///
+    /// Returns [`ControllerError::InvalidLine`] if `line_num` is greater than [`ControllerLine::MAX_LINE_NUM`].
+    pub const fn try_line(&self, line_num: u8) -> Result<ControllerLine, ControllerError> {
+        if line_num > ControllerLine::MAX_LINE_NUM {
+            return Err(ControllerError::InvalidLine { line: line_num });
+        }
 
-        ControllerLine {
+        Ok(ControllerLine {
             controller: *self,
             line: line_num,
+        })
+    }
+
+    /// Returns a line on the controller display that can be used to print to the controller.
+    ///
+    /// # Panics
+    ///
+    /// Unlike [`Controller::try_line`], this function will panic
+    /// if `line_num` is greater than [`ControllerLine::MAX_LINE_NUM`].
+    pub fn line(&self, line_num: u8) -> ControllerLine {
+        self.try_line(line_num).unwrap()
+    }
+
+    /// Rumbles the controller using the given pattern.
+    ///
+    /// The pattern is made of the characters `.` (a short rumble), `-` (a long rumble),
+    /// and ` ` (a pause), and can be at most [`Controller::MAX_RUMBLE_LENGTH`] characters long.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ControllerError::InvalidRumblePattern`] if the pattern contains any other
+    /// characters or is too long, since the controller would otherwise silently ignore it.
+    ///
+    /// Rumbling uses the same update slot as the controller display, so this returns
+    /// [`ControllerError::WriteThrottled`] if the display was written to or the controller
+    /// rumbled within [`ControllerLine::MIN_WRITE_INTERVAL`].
+    pub fn rumble(&self, pattern: &str) -> Result<(), ControllerError> {
+        if !is_valid_rumble_pattern(pattern) {
+            return Err(ControllerError::InvalidRumblePattern);
         }
+
+        // The pattern only contains ASCII characters, so it can't contain a null byte.
+        let c_pattern = CString::new(pattern).unwrap();
+        self.throttle_display_write(|| unsafe {
+            pros_sys::controller_rumble(self.id(), c_pattern.as_ptr())
+        })
+    }
+
+    /// Returns `true` if the controller is connected to the brain.
+    pub fn is_connected(&self) -> Result<bool, ControllerError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::controller_is_connected(self.id())
+        }) == 1)
+    }
+
+    /// Gets the battery level of the controller.
+    pub fn battery_level(&self) -> Result<i32, ControllerError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::controller_get_battery_level(self.id())
+        }))
+    }
+
+    /// Gets the battery capacity of the controller.
+    pub fn battery_capacity(&self) -> Result<i32, ControllerError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::controller_get_battery_capacity(self.id())
+        }))
     }
 
     /// Gets the current state of the controller in its entirety.
+    ///
+    /// This includes the controller's connection status and battery information
+    /// as well as the state of every button and joystick.
     pub fn state(&self) -> Result<ControllerState, ControllerError> {
         Ok(ControllerState {
-            joysticks: unsafe {
-                Joysticks {
-                    left: Joystick {
-                        x: bail_on!(
-                            PROS_ERR,
-                            pros_sys::controller_get_analog(
-                                self.id(),
-                                pros_sys::E_CONTROLLER_ANALOG_LEFT_X,
-                            )
-                        ) as f32
-                            / 127.0,
-                        y: bail_on!(
-                            PROS_ERR,
-                            pros_sys::controller_get_analog(
-                                self.id(),
-                                pros_sys::E_CONTROLLER_ANALOG_LEFT_Y,
-                            )
-                        ) as f32
-                            / 127.0,
-                    },
-                    right: Joystick {
-                        x: bail_on!(
-                            PROS_ERR,
-                            pros_sys::controller_get_analog(
-                                self.id(),
-                                pros_sys::E_CONTROLLER_ANALOG_RIGHT_X,
-                            )
-                        ) as f32
-                            / 127.0,
-                        y: bail_on!(
-                            PROS_ERR,
-                            pros_sys::controller_get_analog(
-                                self.id(),
-                                pros_sys::E_CONTROLLER_ANALOG_RIGHT_Y,
-                            )
-                        ) as f32
-                            / 127.0,
-                    },
-                }
+            connected: self.is_connected()?,
+            battery_level: self.battery_level()?,
+            battery_capacity: self.battery_capacity()?,
+            joysticks: Joysticks {
+                left: Joystick {
+                    x: self.joystick_axis(JoystickAxis::LeftX)?,
+                    y: self.joystick_axis(JoystickAxis::LeftY)?,
+                },
+                right: Joystick {
+                    x: self.joystick_axis(JoystickAxis::RightX)?,
+                    y: self.joystick_axis(JoystickAxis::RightY)?,
+                },
             },
-            buttons: unsafe {
-                Buttons {
-                    a: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_A,
-                        )
-                    ) == 1,
-                    b: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_B,
-                        )
-                    ) == 1,
-                    x: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_X,
-                        )
-                    ) == 1,
-                    y: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_Y,
-                        )
-                    ) == 1,
-                    up: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_UP,
-                        )
-                    ) == 1,
-                    down: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_DOWN,
-                        )
-                    ) == 1,
-                    left: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_LEFT,
-                        )
-                    ) == 1,
-                    right: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_RIGHT,
-                        )
-                    ) == 1,
-                    left_trigger_1: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_L1,
-                        )
-                    ) == 1,
-                    left_trigger_2: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_L2,
-                        )
-                    ) == 1,
-                    right_trigger_1: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_R1,
-                        )
-                    ) == 1,
-                    right_trigger_2: bail_on!(
-                        PROS_ERR,
-                        pros_sys::controller_get_digital(
-                            self.id(),
-                            pros_sys::E_CONTROLLER_DIGITAL_R2,
-                        )
-                    ) == 1,
-                }
+            buttons: Buttons {
+                a: self.button(ControllerButton::A)?,
+                b: self.button(ControllerButton::B)?,
+                x: self.button(ControllerButton::X)?,
+                y: self.button(ControllerButton::Y)?,
+                up: self.button(ControllerButton::Up)?,
+                down: self.button(ControllerButton::Down)?,
+                left: self.button(ControllerButton::Left)?,
+                right: self.button(ControllerButton::Right)?,
+                left_trigger_1: self.button(ControllerButton::LeftTrigger1)?,
+                left_trigger_2: self.button(ControllerButton::LeftTrigger2)?,
+                right_trigger_1: self.button(ControllerButton::RightTrigger1)?,
+                right_trigger_2: self.button(ControllerButton::RightTrigger2)?,
             },
         })
     }
@@ -340,6 +491,46 @@ pub enum ControllerError {
 
     /// Another resource is already using the controller.
     ConcurrentAccess,
+
+    #[snafu(display(
+        "Text of length {len} starting at column {column} is too long to fit on the controller display."
+    ))]
+    /// The text given does not fit on a line of the controller display.
+    TextTooLong {
+        /// The length of the text, in bytes.
+        len: usize,
+        /// The column that the text started at.
+        column: u8,
+    },
+
+    /// The controller display was written to less than
+    /// [`ControllerLine::MIN_WRITE_INTERVAL`] ago, so the write would have been dropped.
+    WriteThrottled,
+
+    /// The rumble pattern contained characters other than `.`, `-` and ` `,
+    /// or was longer than [`Controller::MAX_RUMBLE_LENGTH`].
+    InvalidRumblePattern,
+
+    #[snafu(display("Line {line} does not exist on the controller display."))]
+    /// The line number given was greater than [`ControllerLine::MAX_LINE_NUM`].
+    InvalidLine {
+        /// The line number that was given.
+        line: u8,
+    },
+
+    #[snafu(display("Threshold {threshold} is out of range. Expected a value from 0.0 to 1.0."))]
+    /// The joystick threshold given was outside of the range [0.0, 1.0].
+    InvalidThreshold {
+        /// The threshold that was given.
+        threshold: f32,
+    },
+
+    #[snafu(display("Exponent {exponent} is invalid. Expected a positive, finite number."))]
+    /// The joystick curve exponent given was not a positive, finite number.
+    InvalidExponent {
+        /// The exponent that was given.
+        exponent: f32,
+    },
 }
 
 map_errno! {
@@ -13,6 +13,7 @@
 //! - [`battery`] provides functions for getting information about the currently connected
 //!   battery.
 //! - [`controller`] provides types for interacting with the V5 controller.
+//! - [`watchdog`] provides a watchdog that stops all motors when user code stalls.
 
 #![no_std]
 
@@ -29,7 +30,9 @@ pub mod peripherals;
 pub mod position;
 pub mod screen;
 pub mod usd;
+pub mod watchdog;
 
 pub use controller::Controller;
 pub use position::Position;
 pub use screen::Screen;
+pub use smart::motor::stop_all_motors;
@@ -24,7 +24,11 @@
 
 use core::sync::atomic::AtomicBool;
 
-use crate::{adi::AdiPort, screen::Screen, smart::SmartPort};
+use crate::{
+    adi::AdiPort,
+    screen::Screen,
+    smart::{SmartDevice, SmartPort},
+};
 
 static PERIPHERALS_TAKEN: AtomicBool = AtomicBool::new(false);
 
@@ -219,10 +223,43 @@ impl DynamicPeripherals {
         if self.adi_slots[port_index] {
             return None;
         }
-        self.smart_ports[port_index] = true;
+        self.adi_slots[port_index] = true;
         Some(unsafe { AdiPort::new(port_index as u8 + 1, None) })
     }
 
+    /// Returns a [`SmartPort`] so that it can be taken again with [`DynamicPeripherals::take_smart_port`].
+    ///
+    /// This is useful for reconfiguring a port at runtime, such as switching a port
+    /// between two different kinds of devices.
+    pub fn return_smart_port(&mut self, port: SmartPort) {
+        self.smart_ports[port.index() as usize - 1] = false;
+    }
+
+    /// Drops a smart device and returns its port so that it can be taken again
+    /// with [`DynamicPeripherals::take_smart_port`].
+    ///
+    /// Devices do not hand back the [`SmartPort`] they were created with,
+    /// so this is the way to reclaim the port of a device that is no longer needed.
+    pub fn reclaim_smart_port(&mut self, device: impl SmartDevice) {
+        let port_index = device.port_index();
+        drop(device);
+        self.smart_ports[port_index as usize - 1] = false;
+    }
+
+    /// Returns an [`AdiPort`] so that it can be taken again with [`DynamicPeripherals::take_adi_port`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the port is on an [`AdiExpander`](crate::smart::expander::AdiExpander)
+    /// rather than the brain, since expander ports are not tracked by this struct.
+    pub fn return_adi_port(&mut self, port: AdiPort) {
+        assert!(
+            port.expander_index().is_none(),
+            "ADI ports on an expander cannot be returned to DynamicPeripherals"
+        );
+        self.adi_slots[port.index() as usize - 1] = false;
+    }
+
     /// Creates a [`Screen`] only if one has not been created before.
     pub fn take_screen(&mut self) -> Option<Screen> {
         if self.screen {
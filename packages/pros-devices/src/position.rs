@@ -32,6 +32,16 @@ impl Position {
         Self::Counts(position)
     }
 
+    /// Creates a position from raw encoder ticks of a device with the given resolution.
+    ///
+    /// [`Position::Counts`] always assumes 4096 ticks per revolution. Smart motors have a
+    /// resolution that depends on their gearset, so use this with
+    /// [`Gearset::ticks_per_revolution`](crate::smart::motor::Gearset::ticks_per_revolution)
+    /// when converting ticks from [`Motor::raw_position`](crate::smart::motor::Motor::raw_position).
+    pub fn from_ticks(ticks: i64, ticks_per_revolution: u32) -> Self {
+        Self::Rotations(ticks as f64 / ticks_per_revolution as f64)
+    }
+
     /// Converts a position into degrees.
     pub fn into_degrees(self) -> f64 {
         match self {
@@ -46,10 +56,17 @@ impl Position {
         match self {
             Self::Degrees(num) => num / 360.0,
             Self::Rotations(num) => num,
-            Self::Counts(num) => num as f64 * 4096.0,
+            Self::Counts(num) => num as f64 / 4096.0,
         }
     }
 
+    /// Converts a position into raw encoder ticks of a device with the given resolution.
+    ///
+    /// See [`Position::from_ticks`] for more details.
+    pub fn into_ticks(self, ticks_per_revolution: u32) -> i64 {
+        (self.into_rotations() * ticks_per_revolution as f64) as i64
+    }
+
     /// Converts a position into counts (raw encoder ticks).
     pub fn into_counts(self) -> i64 {
         match self {
@@ -4,8 +4,9 @@
 //! The [`Fill`] trait can be used to draw shapes and text to the screen.
 
 use alloc::{ffi::CString, string::String, vec::Vec};
+use core::time::Duration;
 
-use pros_core::{bail_on, map_errno};
+use pros_core::{bail_on, map_errno, task::delay};
 use pros_sys::PROS_ERR;
 use snafu::Snafu;
 
@@ -422,8 +423,15 @@ impl Screen {
         shape.stroke(self, color)
     }
 
+    /// Fill a rectangle on the screen with a color.
+    ///
+    /// This is shorthand for [`Screen::fill`] with a [`Rect`].
+    pub fn fill_rect(&mut self, rect: Rect, color: impl IntoRgb) -> Result<(), ScreenError> {
+        self.fill(&rect, color)
+    }
+
     /// Wipe the entire display buffer, filling it with a specified color.
-    pub fn erase(color: impl IntoRgb) -> Result<(), ScreenError> {
+    pub fn erase(&mut self, color: impl IntoRgb) -> Result<(), ScreenError> {
         bail_on!(PROS_ERR as u32, unsafe {
             pros_sys::screen_set_eraser(color.into_rgb().into())
         });
@@ -433,7 +441,10 @@ impl Screen {
     }
 
     /// Draw a color to a specified pixel position on the screen.
-    pub fn draw_pixel(x: i16, y: i16) -> Result<(), ScreenError> {
+    pub fn draw_pixel(&mut self, x: i16, y: i16, color: impl IntoRgb) -> Result<(), ScreenError> {
+        bail_on!(PROS_ERR as u32, unsafe {
+            pros_sys::screen_set_pen(color.into_rgb().into())
+        });
         bail_on!(PROS_ERR as u32, unsafe {
             pros_sys::screen_draw_pixel(x, y)
         });
@@ -441,6 +452,36 @@ impl Screen {
         Ok(())
     }
 
+    /// Print text at a pixel position on the screen.
+    ///
+    /// Unlike drawing a [`Text`], this returns [`ScreenError::NulInText`] rather than
+    /// panicking if `text` contains a null byte.
+    pub fn print_at(
+        &mut self,
+        x: i16,
+        y: i16,
+        format: TextFormat,
+        text: &str,
+        color: impl IntoRgb,
+    ) -> Result<(), ScreenError> {
+        let text = CString::new(text).map_err(|_| ScreenError::NulInText)?;
+        bail_on!(PROS_ERR as u32, unsafe {
+            pros_sys::screen_set_pen(color.into_rgb().into())
+        });
+        bail_on!(PROS_ERR as u32, unsafe {
+            pros_sys::screen_print_at(format.into(), x, y, text.as_ptr())
+        });
+
+        Ok(())
+    }
+
+    /// Draw white, medium-sized text at a pixel position on the screen.
+    ///
+    /// Use [`Screen::print_at`] to choose the size and color of the text.
+    pub fn draw_text(&mut self, text: &str, x: i16, y: i16) -> Result<(), ScreenError> {
+        self.print_at(x, y, TextFormat::Medium, text, Rgb::WHITE)
+    }
+
     /// Draw a buffer of pixel colors to a specified region of the screen.
     pub fn draw_buffer<T, I>(
         &mut self,
@@ -540,6 +581,98 @@ impl Screen {
     pub fn touch_status(&self) -> Result<TouchEvent, ScreenError> {
         unsafe { pros_sys::screen_touch_status() }.try_into()
     }
+
+    /// Returns a handle for reading touch input from the screen.
+    ///
+    /// Reading touch input doesn't affect what is drawn on the screen, so the returned
+    /// handle can be used separately from the [`Screen`], even after it has been moved
+    /// into something else that draws to the display.
+    pub const fn touch_screen(&self) -> TouchScreen {
+        TouchScreen { _private: () }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// A read-only handle to the touch input of the brain's screen.
+///
+/// Created with [`Screen::touch_screen`].
+pub struct TouchScreen {
+    _private: (),
+}
+
+impl TouchScreen {
+    /// Get the current touch status of the screen.
+    pub fn touch_status(&self) -> Result<TouchEvent, ScreenError> {
+        unsafe { pros_sys::screen_touch_status() }.try_into()
+    }
+
+    /// Waits for the next time the screen is pressed, returning the touch event of that press.
+    ///
+    /// Only presses that happen after the future is first polled are reported.
+    /// The screen is checked every 10ms, sleeping with the async runtime's reactor in between.
+    #[cfg(feature = "async")]
+    pub async fn wait_for_touch(&self) -> Result<TouchEvent, ScreenError> {
+        let press_count = self.touch_status()?.press_count;
+        loop {
+            pros_async::sleep(TOUCH_POLL_INTERVAL).await;
+
+            let event = self.touch_status()?;
+            if event.press_count != press_count {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Returns an iterator over touch events, for reading touch input without callbacks or async code.
+    ///
+    /// Each call to [`Iterator::next`] blocks the current task until the screen is pressed or
+    /// released, checking every 10ms. The iterator never ends.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pros::prelude::*;
+    /// # let touch = Peripherals::take().unwrap().screen.touch_screen();
+    /// for event in touch.events() {
+    ///     let event = event?;
+    ///     if event.state == TouchState::Pressed {
+    ///         println!("Pressed at ({}, {})", event.x, event.y);
+    ///     }
+    /// }
+    /// ```
+    pub const fn events(&self) -> TouchEvents {
+        TouchEvents { last: None }
+    }
+}
+
+/// How often touch input is checked while waiting for it to change.
+const TOUCH_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Debug, Clone, Copy)]
+/// A blocking iterator over presses and releases of the screen,
+/// created with [`TouchScreen::events`].
+pub struct TouchEvents {
+    /// The press and release counts of the last reported event.
+    last: Option<(i32, i32)>,
+}
+
+impl Iterator for TouchEvents {
+    type Item = Result<TouchEvent, ScreenError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let event: TouchEvent = match unsafe { pros_sys::screen_touch_status() }.try_into() {
+                Ok(event) => event,
+                Err(err) => return Some(Err(err)),
+            };
+            let counts = (event.press_count, event.release_count);
+
+            match self.last.replace(counts) {
+                Some(last) if last != counts => return Some(Ok(event)),
+                _ => delay(TOUCH_POLL_INTERVAL),
+            }
+        }
+    }
 }
 
 #[derive(Debug, Snafu)]
@@ -548,6 +681,9 @@ pub enum ScreenError {
     /// Another resource is currently trying to access the screen mutex.
     ConcurrentAccess,
 
+    /// The text to print contained a null byte.
+    NulInText,
+
     /// The given buffer of colors was wrong size to fill the specified area.
     CopyBufferWrongSize {
         /// The size of the buffer.
@@ -17,6 +17,9 @@ pub struct DistanceSensor {
 }
 
 impl DistanceSensor {
+    /// The distance reported by the sensor when it does not detect an object.
+    const NO_OBJECT_DISTANCE: i32 = 9999;
+
     /// Create a new distance sensor from a smart port index.
     pub const fn new(port: SmartPort) -> Self {
         Self { port }
@@ -64,6 +67,53 @@ impl DistanceSensor {
 
         Ok(confidence / 63.0)
     }
+
+    /// Returns a snapshot of every measurement of the object the sensor detects,
+    /// or `None` if the sensor does not currently detect an object.
+    ///
+    /// All of the measurements are read together, so a control loop gets one consistent
+    /// reading instead of calling [`DistanceSensor::distance`], [`DistanceSensor::velocity`],
+    /// [`DistanceSensor::relative_size`] and [`DistanceSensor::distance_confidence`] separately.
+    pub fn reading(&self) -> Result<Option<DistanceReading>, PortError> {
+        let port = self.port.index();
+        let (distance, velocity, relative_size, confidence) = unsafe {
+            (
+                bail_on!(PROS_ERR, pros_sys::distance_get(port)),
+                bail_on!(
+                    PROS_ERR as c_double,
+                    pros_sys::distance_get_object_velocity(port)
+                ),
+                bail_on!(PROS_ERR, pros_sys::distance_get_object_size(port)),
+                bail_on!(PROS_ERR, pros_sys::distance_get_confidence(port)),
+            )
+        };
+
+        if distance == Self::NO_OBJECT_DISTANCE || relative_size < 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(DistanceReading {
+            distance: distance as f64,
+            velocity,
+            relative_size: relative_size as f64,
+            confidence: confidence as f64 / 63.0,
+        }))
+    }
+}
+
+/// A snapshot of the measurements of an object detected by a [`DistanceSensor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistanceReading {
+    /// The distance to the object in millimeters.
+    pub distance: f64,
+    /// The velocity of the object in m/s.
+    pub velocity: f64,
+    /// The relative size of the object from 0 to 400.
+    ///
+    /// See [`DistanceSensor::relative_size`] for more information.
+    pub relative_size: f64,
+    /// The confidence in the distance measurement from 0.0 to 1.0.
+    pub confidence: f64,
 }
 
 impl SmartDevice for DistanceSensor {
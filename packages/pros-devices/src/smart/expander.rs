@@ -38,7 +38,10 @@ pub struct AdiExpander {
 
 impl AdiExpander {
     /// Create a new expander from a smart port index.
-    pub fn new(port: SmartPort) -> Self {
+    ///
+    /// Every [`AdiPort`] on the expander routes its device calls through `port`, so any ADI device
+    /// can be created from them just like the brain's onboard ports.
+    pub const fn new(port: SmartPort) -> Self {
         unsafe {
             Self {
                 adi_a: AdiPort::new(1, Some(port.index())),
@@ -1,7 +1,7 @@
 //! GPS sensor device.
 //!
-//! A notable differenc between this API and that of PROS
-//! is that [`GpsSensor::status`] returns acceleration along with other status data.
+//! A notable difference between this API and that of PROS
+//! is that [`GpsSensor::status`] returns acceleration and gyro rates along with other status data.
 
 use pros_core::{bail_on, error::PortError, map_errno};
 use pros_sys::{PROS_ERR, PROS_ERR_F};
@@ -32,6 +32,25 @@ pub struct GpsStatus {
     pub accel_y: f64,
     /// The z-acceleration of the GPS sensor.
     pub accel_z: f64,
+
+    /// The rotation rate of the GPS sensor around the x axis.
+    pub gyro_x: f64,
+    /// The rotation rate of the GPS sensor around the y axis.
+    pub gyro_y: f64,
+    /// The rotation rate of the GPS sensor around the z axis.
+    pub gyro_z: f64,
+}
+
+/// The position and heading of a GPS sensor on the field.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct GpsPose {
+    /// The x-coordinate of the GPS sensor in meters, with 0 being the center of the field.
+    pub x: f64,
+    /// The y-coordinate of the GPS sensor in meters, with 0 being the center of the field.
+    pub y: f64,
+    /// The heading of the GPS sensor in degrees, bounded to [0, 360).
+    /// 0 is north on the field and angles increase clockwise.
+    pub heading: f64,
 }
 
 /// A physical GPS sensor plugged into a port.
@@ -42,18 +61,34 @@ pub struct GpsSensor {
 
 impl GpsSensor {
     /// Creates a new GPS sensor on the given port.
-    pub fn new(port: SmartPort) -> Result<Self, GpsError> {
+    ///
+    /// `offset_x` and `offset_y` are the position of the sensor relative to the robot's
+    /// center of turning, in meters. `initial_pose` is where the robot starts on the field,
+    /// which helps the sensor until it can see enough of the field strip to locate itself.
+    pub fn new(
+        port: SmartPort,
+        offset_x: f64,
+        offset_y: f64,
+        initial_pose: GpsPose,
+    ) -> Result<Self, GpsError> {
         unsafe {
             bail_on!(
                 PROS_ERR,
-                pros_sys::gps_initialize_full(port.index(), 0.0, 0.0, 0.0, 0.0, 0.0)
+                pros_sys::gps_initialize_full(
+                    port.index(),
+                    initial_pose.x,
+                    initial_pose.y,
+                    initial_pose.heading,
+                    offset_x,
+                    offset_y
+                )
             );
         }
 
         Ok(Self { port })
     }
 
-    /// Sets the offset of the GPS sensor, relative to the sensor of turning, in meters.
+    /// Sets the offset of the GPS sensor, relative to the center of turning, in meters.
     pub fn set_offset(&mut self, x: f64, y: f64) -> Result<(), GpsError> {
         unsafe {
             bail_on!(PROS_ERR, pros_sys::gps_set_offset(self.port.index(), x, y));
@@ -61,8 +96,54 @@ impl GpsSensor {
         Ok(())
     }
 
-    /// Gets the possible error of the GPS sensor, in meters.
-    pub fn rms_error(&self) -> Result<f64, GpsError> {
+    /// Gets the offset of the GPS sensor, relative to the center of turning, in meters.
+    pub fn offset(&self) -> Result<(f64, f64), GpsError> {
+        let mut x = 0.0;
+        let mut y = 0.0;
+        unsafe {
+            bail_on!(
+                PROS_ERR,
+                pros_sys::gps_get_offset(self.port.index(), &mut x, &mut y)
+            );
+        }
+        Ok((x, y))
+    }
+
+    /// Gets the `(x, y)` position of the GPS sensor on the field, in meters.
+    ///
+    /// The origin is the center of the field.
+    pub fn position(&self) -> Result<(f64, f64), GpsError> {
+        unsafe {
+            let status = pros_sys::gps_get_status(self.port.index());
+            bail_on!(PROS_ERR_F, status.x);
+            Ok((status.x, status.y))
+        }
+    }
+
+    /// Gets the position and heading of the GPS sensor on the field.
+    pub fn pose(&self) -> Result<GpsPose, GpsError> {
+        unsafe {
+            let status = pros_sys::gps_get_status(self.port.index());
+            bail_on!(PROS_ERR_F, status.x);
+            let heading = bail_on!(PROS_ERR_F, pros_sys::gps_get_heading(self.port.index()));
+
+            Ok(GpsPose {
+                x: status.x,
+                y: status.y,
+                heading,
+            })
+        }
+    }
+
+    /// Gets the heading of the GPS sensor in degrees, bounded to [0, 360).
+    ///
+    /// This is the direction the sensor is facing relative to the field, with 0 being north.
+    pub fn heading(&self) -> Result<f64, GpsError> {
+        Ok(unsafe { bail_on!(PROS_ERR_F, pros_sys::gps_get_heading(self.port.index())) })
+    }
+
+    /// Gets the RMS error of the GPS sensor's position, in meters.
+    pub fn error(&self) -> Result<f64, GpsError> {
         Ok(unsafe { bail_on!(PROS_ERR_F, pros_sys::gps_get_error(self.port.index())) })
     }
 
@@ -73,6 +154,8 @@ impl GpsSensor {
             bail_on!(PROS_ERR_F, status.x);
             let accel = pros_sys::gps_get_accel(self.port.index());
             bail_on!(PROS_ERR_F, accel.x);
+            let gyro = pros_sys::gps_get_gyro_rate(self.port.index());
+            bail_on!(PROS_ERR_F, gyro.x);
             let heading = bail_on!(PROS_ERR_F, pros_sys::gps_get_heading(self.port.index()));
 
             Ok(GpsStatus {
@@ -86,6 +169,10 @@ impl GpsSensor {
                 accel_x: accel.x,
                 accel_y: accel.y,
                 accel_z: accel.z,
+
+                gyro_x: gyro.x,
+                gyro_y: gyro.y,
+                gyro_z: gyro.z,
             })
         }
     }
@@ -41,9 +41,14 @@ impl InertialSensor {
     /// This takes approximately 2 seconds, and is blocking until the IMU status flag is set properly.
     /// There is additionally a 3 second timeout that will return [`InertialError::CalibrationTimedOut`] if the timeout is exceeded.
     pub fn calibrate_blocking(&mut self) -> Result<(), InertialError> {
-        bail_on!(PROS_ERR, unsafe {
-            pros_sys::imu_reset_blocking(self.port.index())
-        });
+        if unsafe { pros_sys::imu_reset_blocking(self.port.index()) } == PROS_ERR {
+            // When resetting, PROS also uses EAGAIN to report that calibration timed out.
+            return Err(match take_errno() {
+                pros_sys::EAGAIN => InertialError::CalibrationTimedOut,
+                errno => InertialError::from_errno(errno)
+                    .unwrap_or_else(|| panic!("Unknown errno code {errno}")),
+            });
+        }
         Ok(())
     }
 
@@ -57,7 +62,7 @@ impl InertialSensor {
     }
 
     /// Check if the Intertial Sensor is currently calibrating.
-    pub fn is_calibrating(&mut self) -> Result<bool, InertialError> {
+    pub fn is_calibrating(&self) -> Result<bool, InertialError> {
         Ok(self.status()?.contains(InertialStatus::CALIBRATING))
     }
 
@@ -421,7 +426,7 @@ impl core::future::Future for InertialCalibrateFuture {
                 let is_calibrating = match unsafe { pros_sys::imu_get_status(port) } {
                     pros_sys::E_IMU_STATUS_ERROR => {
                         let errno = take_errno();
-                        return Poll::Ready(Err(InertialError::from_errno(take_errno())
+                        return Poll::Ready(Err(InertialError::from_errno(errno)
                             .unwrap_or_else(|| panic!("Unknown errno code {errno}"))));
                     }
                     value => (value & pros_sys::E_IMU_STATUS_CALIBRATING) != 0,
@@ -445,6 +450,8 @@ impl core::future::Future for InertialCalibrateFuture {
 pub enum InertialError {
     /// The inertial sensor spent too long calibrating.
     CalibrationTimedOut,
+    /// The inertial sensor is still calibrating, so its data can't be read yet.
+    StillCalibrating,
     /// Invalid sensor data rate, expected >= 5 milliseconds.
     InvalidDataRate,
     #[snafu(display("{source}"), context(false))]
@@ -457,7 +464,7 @@ pub enum InertialError {
 
 map_errno! {
     InertialError {
-        EAGAIN => Self::CalibrationTimedOut,
+        EAGAIN => Self::StillCalibrating,
     }
     inherit PortError;
 }
@@ -28,6 +28,9 @@ pub trait Link: SmartDevice {
     }
 
     /// Create a new link ready to send or recieve data.
+    ///
+    /// If `vexlink_override` is `true`, the radio will be used for VEXLink even if it is
+    /// also being used to connect the brain to a controller.
     fn new(port: SmartPort, id: String, vexlink_override: bool) -> Result<Self, LinkError>
     where
         Self: Sized;
@@ -95,9 +98,9 @@ impl Link for RxLink {
             bail_on!(
                 pros_sys::PROS_ERR as _,
                 if vexlink_override {
-                    pros_sys::link_init(port.index(), id.as_ptr().cast(), E_LINK_RECEIVER)
-                } else {
                     pros_sys::link_init_override(port.index(), id.as_ptr().cast(), E_LINK_RECEIVER)
+                } else {
+                    pros_sys::link_init(port.index(), id.as_ptr().cast(), E_LINK_RECEIVER)
                 }
             )
         };
@@ -117,10 +120,12 @@ impl SmartDevice for RxLink {
 
 impl io::Read for RxLink {
     fn read(&mut self, dst: &mut [u8]) -> io::Result<usize> {
-        let bytes_read = self
-            .receive(dst)
-            .map_err(|_| io::Error::new(io::ErrorKind::Other, "failed to read from link"))?;
-        Ok(bytes_read as _)
+        match self.receive(dst) {
+            Ok(bytes_read) => Ok(bytes_read as _),
+            // Nothing has been received yet.
+            Err(LinkError::Busy) => Ok(0),
+            Err(err) => Err(err.into()),
+        }
     }
 }
 
@@ -132,6 +137,9 @@ pub struct TxLink {
 }
 
 impl TxLink {
+    /// The maximum number of bytes that can be sent in a single transmission.
+    pub const MAX_TRANSMISSION_SIZE: usize = 512;
+
     // I have literally no idea what the purpose of this is,
     // there is no way to push to the transmission buffer without transmitting it.
     /// Get the number of bytes to be sent over this link.
@@ -147,9 +155,16 @@ impl TxLink {
     }
 
     /// Transmit a buffer of data over the link.
+    ///
+    /// Returns [`LinkError::TransmissionTooLarge`] rather than truncating the data if `buf` is longer
+    /// than [`TxLink::MAX_TRANSMISSION_SIZE`], and [`LinkError::NoLink`] if the other robot is not connected.
     pub fn transmit(&self, buf: &[u8]) -> Result<u32, LinkError> {
         const PROS_ERR_U32: u32 = pros_sys::PROS_ERR as _;
 
+        if buf.len() > Self::MAX_TRANSMISSION_SIZE {
+            return Err(LinkError::TransmissionTooLarge { len: buf.len() });
+        }
+
         match unsafe { link_transmit(self.port.index(), buf.as_ptr().cast(), buf.len() as _) } {
             PROS_ERR_U32 => {
                 let errno = pros_core::error::take_errno();
@@ -164,10 +179,7 @@ impl TxLink {
 
 impl io::Write for TxLink {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let bytes_written = self
-            .transmit(buf)
-            .map_err(|_| io::Error::new(io::ErrorKind::Other, "failed to write to link"))?;
-        Ok(bytes_written as _)
+        Ok(self.transmit(buf)? as _)
     }
     fn flush(&mut self) -> io::Result<()> {
         Ok(())
@@ -184,13 +196,13 @@ impl Link for TxLink {
             bail_on!(
                 pros_sys::PROS_ERR as _,
                 if vexlink_override {
-                    pros_sys::link_init(port.index(), id.as_ptr().cast(), E_LINK_TRANSMITTER)
-                } else {
                     pros_sys::link_init_override(
                         port.index(),
                         id.as_ptr().cast(),
                         E_LINK_TRANSMITTER,
                     )
+                } else {
+                    pros_sys::link_init(port.index(), id.as_ptr().cast(), E_LINK_TRANSMITTER)
                 }
             )
         };
@@ -221,6 +233,15 @@ pub enum LinkError {
     Protocol,
     /// The link is busy.
     Busy,
+    #[snafu(display(
+        "Cannot transmit {len} bytes at once, the maximum is {} bytes.",
+        TxLink::MAX_TRANSMISSION_SIZE
+    ))]
+    /// The data was too large to send in a single transmission.
+    TransmissionTooLarge {
+        /// The length of the data that was attempted to be sent.
+        len: usize,
+    },
     #[snafu(display("{source}"), context(false))]
     /// Generic port related error
     Port {
@@ -229,6 +250,24 @@ pub enum LinkError {
     },
 }
 
+impl From<LinkError> for io::Error {
+    fn from(err: LinkError) -> Self {
+        match err {
+            LinkError::NoLink => Self::new(io::ErrorKind::NotConnected, "no link is connected"),
+            LinkError::BufferBusyFull => {
+                Self::new(io::ErrorKind::WouldBlock, "link transmit buffer is full")
+            }
+            LinkError::NullData => Self::new(io::ErrorKind::InvalidInput, "invalid link data"),
+            LinkError::Protocol => Self::new(io::ErrorKind::InvalidData, "link protocol error"),
+            LinkError::Busy => Self::new(io::ErrorKind::WouldBlock, "link is busy"),
+            LinkError::TransmissionTooLarge { .. } => {
+                Self::new(io::ErrorKind::InvalidInput, "link transmission too large")
+            }
+            LinkError::Port { source } => source.into(),
+        }
+    }
+}
+
 map_errno! {
     LinkError {
         ENXIO => Self::NoLink,
@@ -26,10 +26,13 @@ pub mod gps;
 pub mod imu;
 pub mod link;
 pub mod motor;
+pub mod motor_group;
 pub mod optical;
 pub mod rotation;
+pub mod serial;
 pub mod vision;
 
+use alloc::vec::Vec;
 use core::fmt;
 
 pub use distance::DistanceSensor;
@@ -38,11 +41,42 @@ pub use gps::GpsSensor;
 pub use imu::InertialSensor;
 pub use link::{Link, RxLink, TxLink};
 pub use motor::Motor;
+pub use motor_group::MotorGroup;
 pub use optical::OpticalSensor;
 use pros_core::{bail_on, error::PortError};
 pub use rotation::RotationSensor;
+pub use serial::SerialPort;
 pub use vision::VisionSensor;
 
+/// The number of smart ports on the V5 brain.
+pub const NUM_SMART_PORTS: u8 = 21;
+
+/// Get the type of device plugged into every smart port on the brain.
+///
+/// Each entry contains a port index (starting from 1) and the result of reading the type of
+/// device plugged into that port. Empty ports are reported as [`SmartDeviceType::None`], and
+/// ports that couldn't be read keep their error. This can be useful for catching wiring
+/// mistakes when the robot starts up.
+///
+/// # Examples
+///
+/// ```
+/// for (port, device_type) in plugged_devices() {
+///     match device_type {
+///         Ok(device_type) => println!("Port {port}: {device_type:?}"),
+///         Err(err) => println!("Port {port}: failed to read device type: {err}"),
+///     }
+/// }
+/// ```
+pub fn plugged_devices() -> Vec<(u8, Result<SmartDeviceType, PortError>)> {
+    (1..=NUM_SMART_PORTS)
+        .map(|index| {
+            // The port is only used to query the device registry, so no device is ever created on it.
+            (index, unsafe { SmartPort::new(index) }.connected_type())
+        })
+        .collect()
+}
+
 /// Defines common functionality shared by all smart port devices.
 pub trait SmartDevice {
     /// Get the index of the [`SmartPort`] this device is registered on.
@@ -70,6 +104,11 @@ pub trait SmartDevice {
     /// Determine if this device type is currently connected to the [`SmartPort`]
     /// that it's registered to.
     ///
+    /// This can be used to detect a device being unplugged (for example, from a cable
+    /// coming loose) without relying on the errors returned by the device's getters.
+    /// The check only reads the brain's device registry, so it is cheap enough to be
+    /// called every loop iteration.
+    ///
     /// # Examples
     ///
     /// ```
@@ -82,14 +121,12 @@ pub trait SmartDevice {
     /// }
     /// ```
     fn port_connected(&self) -> bool {
-        let plugged_type_result: Result<SmartDeviceType, _> =
-            unsafe { pros_sys::apix::registry_get_plugged_type(self.port_index() - 1).try_into() };
+        // Compare the raw device IDs so that unrecognized devices are treated as
+        // a mismatch rather than failing to convert into a `SmartDeviceType`.
+        let plugged_type =
+            unsafe { pros_sys::apix::registry_get_plugged_type(self.port_index() - 1) };
 
-        if let Ok(plugged_type) = plugged_type_result {
-            plugged_type == self.device_type()
-        } else {
-            false
-        }
+        plugged_type == self.device_type().into()
     }
 }
 
@@ -152,6 +189,30 @@ impl SmartPort {
         unsafe { pros_sys::apix::registry_get_plugged_type(self.index() - 1).try_into() }
     }
 
+    /// Get the type of device currently plugged into this port, or `None` if the port is empty.
+    ///
+    /// Unlike [`SmartPort::connected_type`], an empty port is represented as `None` rather than
+    /// [`SmartDeviceType::None`], making this useful for checking what hardware is present
+    /// before constructing a device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let my_port = unsafe { SmartPort::new(1) };
+    ///
+    /// match my_port.installed_type()? {
+    ///     Some(SmartDeviceType::Motor) => println!("Found a motor on port 1."),
+    ///     Some(other) => println!("Expected a motor on port 1, found {other:?}."),
+    ///     None => println!("Nothing is plugged into port 1."),
+    /// }
+    /// ```
+    pub fn installed_type(&self) -> Result<Option<SmartDeviceType>, PortError> {
+        Ok(match self.connected_type()? {
+            SmartDeviceType::None => None,
+            device_type => Some(device_type),
+        })
+    }
+
     /// Get the type of device this port is configured as.
     ///
     /// # Examples
@@ -169,42 +230,52 @@ impl SmartPort {
 
 /// Represents a possible type of device that can be registered on a [`SmartPort`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
 pub enum SmartDeviceType {
     /// No device
-    None = pros_sys::apix::E_DEVICE_NONE,
+    None,
 
     /// Smart Motor
-    Motor = pros_sys::apix::E_DEVICE_MOTOR,
+    Motor,
 
     /// Rotation Sensor
-    Rotation = pros_sys::apix::E_DEVICE_ROTATION,
+    Rotation,
 
     /// Inertial Sensor
-    Imu = pros_sys::apix::E_DEVICE_IMU,
+    Imu,
 
     /// Distance Sensor
-    Distance = pros_sys::apix::E_DEVICE_DISTANCE,
+    Distance,
 
     /// Vision Sensor
-    Vision = pros_sys::apix::E_DEVICE_VISION,
+    Vision,
 
     /// Optical Sensor
-    Optical = pros_sys::apix::E_DEVICE_OPTICAL,
+    Optical,
 
     /// GPS Sensor
-    Gps = pros_sys::apix::E_DEVICE_GPS,
+    Gps,
+
+    /// Workcell Electromagnet
+    ///
+    /// PROS does not currently expose an API for controlling electromagnets,
+    /// so this variant is only reported when detecting plugged in devices.
+    Electromagnet,
 
     /// Smart Radio
-    Radio = pros_sys::apix::E_DEVICE_RADIO,
+    Radio,
 
     /// ADI Expander
     ///
     /// This variant is also internally to represent the brain's onboard ADI slots.
-    Adi = pros_sys::apix::E_DEVICE_ADI,
+    Adi,
 
     /// Generic Serial Port
-    Serial = pros_sys::apix::E_DEVICE_SERIAL,
+    Serial,
+
+    /// A device that pros-rs doesn't recognize, such as a sensor released after this version.
+    ///
+    /// Contains the raw device ID reported by the brain's device registry.
+    Unknown(pros_sys::apix::v5_device_e_t),
 }
 
 impl TryFrom<pros_sys::apix::v5_device_e_t> for SmartDeviceType {
@@ -226,10 +297,12 @@ impl TryFrom<pros_sys::apix::v5_device_e_t> for SmartDeviceType {
             pros_sys::apix::E_DEVICE_DISTANCE => Self::Distance,
             pros_sys::apix::E_DEVICE_VISION => Self::Vision,
             pros_sys::apix::E_DEVICE_OPTICAL => Self::Optical,
+            pros_sys::apix::E_DEVICE_GPS => Self::Gps,
+            pros_sys::apix::E_DEVICE_MAGNET => Self::Electromagnet,
             pros_sys::apix::E_DEVICE_RADIO => Self::Radio,
             pros_sys::apix::E_DEVICE_ADI => Self::Adi,
             pros_sys::apix::E_DEVICE_SERIAL => Self::Serial,
-            _ => unreachable!(),
+            unknown => Self::Unknown(unknown),
         })
     }
 }
@@ -237,7 +310,21 @@ impl TryFrom<pros_sys::apix::v5_device_e_t> for SmartDeviceType {
 impl From<SmartDeviceType> for pros_sys::apix::v5_device_e_t {
     /// Convert a [`SmartDeviceType`] into a raw `pros_sys::apix::v5_device_e_t`.
     fn from(value: SmartDeviceType) -> Self {
-        value as _
+        match value {
+            SmartDeviceType::None => pros_sys::apix::E_DEVICE_NONE,
+            SmartDeviceType::Motor => pros_sys::apix::E_DEVICE_MOTOR,
+            SmartDeviceType::Rotation => pros_sys::apix::E_DEVICE_ROTATION,
+            SmartDeviceType::Imu => pros_sys::apix::E_DEVICE_IMU,
+            SmartDeviceType::Distance => pros_sys::apix::E_DEVICE_DISTANCE,
+            SmartDeviceType::Vision => pros_sys::apix::E_DEVICE_VISION,
+            SmartDeviceType::Optical => pros_sys::apix::E_DEVICE_OPTICAL,
+            SmartDeviceType::Gps => pros_sys::apix::E_DEVICE_GPS,
+            SmartDeviceType::Electromagnet => pros_sys::apix::E_DEVICE_MAGNET,
+            SmartDeviceType::Radio => pros_sys::apix::E_DEVICE_RADIO,
+            SmartDeviceType::Adi => pros_sys::apix::E_DEVICE_ADI,
+            SmartDeviceType::Serial => pros_sys::apix::E_DEVICE_SERIAL,
+            SmartDeviceType::Unknown(raw) => raw,
+        }
     }
 }
 
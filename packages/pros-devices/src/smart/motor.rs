@@ -7,7 +7,7 @@ use pros_core::{bail_on, error::PortError, map_errno};
 use pros_sys::{PROS_ERR, PROS_ERR_F};
 use snafu::Snafu;
 
-use super::{SmartDevice, SmartDeviceTimestamp, SmartDeviceType, SmartPort};
+use super::{SmartDevice, SmartDeviceTimestamp, SmartDeviceType, SmartPort, NUM_SMART_PORTS};
 use crate::Position;
 
 /// The basic motor struct.
@@ -146,6 +146,9 @@ impl Motor {
     }
 
     /// Sets the motors target to a given [`BrakeMode`].
+    ///
+    /// Unlike setting a velocity of zero, this does not use the motor's internal velocity
+    /// controller. [`BrakeMode::None`] lets the motor coast freely.
     pub fn brake(&mut self, mode: BrakeMode) -> Result<(), MotorError> {
         self.set_target(MotorControl::Brake(mode))
     }
@@ -168,6 +171,10 @@ impl Motor {
     }
 
     /// Sets an absolute position target for the motor to attempt to reach.
+    ///
+    /// The motor will follow a trapezoidal motion profile to reach `position`, moving no faster than
+    /// `velocity` RPM. Positions are measured at the output shaft, so the same [`Position`] turns the
+    /// shaft the same amount regardless of the motor's [`Gearset`].
     pub fn set_position_target(
         &mut self,
         position: Position,
@@ -176,6 +183,19 @@ impl Motor {
         self.set_target(MotorControl::Position(position, velocity))
     }
 
+    /// Sets a position target for the motor relative to its current position.
+    ///
+    /// The resulting absolute target is reported by [`Motor::target`]. See
+    /// [`Motor::set_position_target`] for details on how the movement is performed.
+    pub fn set_relative_position_target(
+        &mut self,
+        offset: Position,
+        velocity: i32,
+    ) -> Result<(), MotorError> {
+        let position = self.position()? + offset;
+        self.set_position_target(position, velocity)
+    }
+
     /// Changes the output velocity for a profiled movement (motor_move_absolute or motor_move_relative).
     ///
     /// This will have no effect if the motor is not following a profiled movement.
@@ -184,18 +204,15 @@ impl Motor {
             pros_sys::motor_modify_profiled_velocity(self.port.index() as i8, velocity)
         });
 
-        match self.target {
-            MotorControl::Position(position, _) => {
-                self.target = MotorControl::Position(position, velocity)
-            }
-            _ => {}
+        if let MotorControl::Position(position, _) = self.target {
+            self.target = MotorControl::Position(position, velocity);
         }
 
         Ok(())
     }
 
     /// Get the current [`MotorControl`] value that the motor is attempting to use.
-    pub fn target(&self) -> MotorControl {
+    pub const fn target(&self) -> MotorControl {
         self.target
     }
 
@@ -253,17 +270,17 @@ impl Motor {
     /// along with a timestamp of the internal clock of the motor indicating when the
     /// data was recorded.
     pub fn raw_position(&self) -> Result<(i32, SmartDeviceTimestamp), MotorError> {
-        let timestamp = 0 as *mut u32;
+        let mut timestamp = 0;
 
         // PROS docs claim that this function gets the position *at* a recorded timestamp,
         // but in reality the "timestamp" paramater is a mutable outvalue. The function
         // outputs the most recent recorded posision AND the timestamp it was measured at,
         // rather than a position at a requested timestamp.
         let ticks = bail_on!(PROS_ERR, unsafe {
-            pros_sys::motor_get_raw_position(self.port.index() as i8, timestamp)
+            pros_sys::motor_get_raw_position(self.port.index() as i8, &mut timestamp)
         });
 
-        Ok((ticks, SmartDeviceTimestamp(unsafe { *timestamp })))
+        Ok((ticks, SmartDeviceTimestamp(timestamp)))
     }
 
     /// Returns the electrical current draw of the motor in amps.
@@ -285,6 +302,15 @@ impl Motor {
         }) / 100.0)
     }
 
+    /// Returns the internal temperature of the motor in degrees Celsius.
+    ///
+    /// The motor starts limiting its output above 55°C. See [`Motor::is_over_temperature`].
+    pub fn temperature(&self) -> Result<f64, MotorError> {
+        Ok(bail_on!(PROS_ERR_F, unsafe {
+            pros_sys::motor_get_temperature(self.port.index() as i8)
+        }))
+    }
+
     /// Sets the current encoder position to zero without moving the motor.
     /// Analogous to taring or resetting the encoder to the current position.
     pub fn zero(&mut self) -> Result<(), MotorError> {
@@ -359,6 +385,9 @@ impl Motor {
     }
 
     /// Get the fault flags of the motor.
+    ///
+    /// A motor that is unplugged returns a [`MotorError::Port`] error rather than a set of faults,
+    /// so an empty [`MotorFaults`] means that the motor is connected and operating normally.
     pub fn faults(&self) -> Result<MotorFaults, MotorError> {
         let bits = bail_on!(PROS_ERR as u32, unsafe {
             pros_sys::motor_get_faults(self.port.index() as i8)
@@ -382,9 +411,9 @@ impl Motor {
         Ok(self.faults()?.contains(MotorFaults::DRIVER_FAULT))
     }
 
-    /// Check if the motor's H-bridge has an overucrrent fault.
+    /// Check if the motor's H-bridge has an overcurrent fault.
     pub fn is_driver_over_current(&self) -> Result<bool, MotorError> {
-        Ok(self.faults()?.contains(MotorFaults::OVER_CURRENT))
+        Ok(self.faults()?.contains(MotorFaults::DRIVER_OVER_CURRENT))
     }
 
     /// Set the [`Direction`] of this motor.
@@ -683,6 +712,25 @@ impl From<MotorTuningConstants> for pros_sys::motor_pid_full_s_t {
     }
 }
 
+/// Stops every motor plugged into the brain by setting its output voltage to zero.
+///
+/// Unlike [`Motor::set_voltage`], this doesn't need access to any [`Motor`]s, so it can be called
+/// from anywhere, such as a panic hook or a [`Watchdog`](crate::watchdog::Watchdog).
+/// Errors are ignored so that every motor is always sent the command.
+///
+/// [`Motor::target`] is not updated for motors that are still in use elsewhere, and they will
+/// start moving again as soon as they are given a new target.
+pub fn stop_all_motors() {
+    let motor_type: pros_sys::apix::v5_device_e_t = SmartDeviceType::Motor.into();
+    for index in 1..=NUM_SMART_PORTS {
+        if unsafe { pros_sys::apix::registry_get_plugged_type(index - 1) } == motor_type {
+            unsafe {
+                pros_sys::motor_move_voltage(index as i8, 0);
+            }
+        }
+    }
+}
+
 #[derive(Debug, Snafu)]
 /// Errors that can occur when using a motor.
 pub enum MotorError {
@@ -0,0 +1,268 @@
+//! Groups of V5 Smart Motors that are controlled together.
+//!
+//! Drivetrains and other mechanisms often use several motors that should always receive the same
+//! commands. A [`MotorGroup`] owns those motors and forwards each command to all of them.
+//!
+//! Unlike running each command with `?` on every motor in turn, a command sent to a motor group
+//! is always sent to **every** motor in the group, even if some of them fail. This avoids
+//! situations where one unplugged motor leaves the rest of a drivetrain running at full speed.
+//! Any failures are collected into a [`MotorGroupError`] along with the port of each motor that failed.
+
+use alloc::vec::Vec;
+use core::{
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use snafu::Snafu;
+
+use super::{
+    motor::{BrakeMode, Direction, Motor, MotorControl, MotorError},
+    SmartDevice,
+};
+use crate::Position;
+
+/// A group of motors that are controlled as a single unit.
+#[derive(Debug, PartialEq)]
+pub struct MotorGroup {
+    motors: Vec<Motor>,
+}
+
+impl MotorGroup {
+    /// Creates a new motor group from the given motors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `motors` is empty. Use [`MotorGroup::try_from`] to handle this case instead.
+    pub fn new(motors: Vec<Motor>) -> Self {
+        assert!(
+            !motors.is_empty(),
+            "a motor group must contain at least one motor"
+        );
+        Self { motors }
+    }
+
+    /// Runs a command on every motor in the group, collecting the errors of any that fail.
+    fn for_each(
+        &mut self,
+        mut command: impl FnMut(&mut Motor) -> Result<(), MotorError>,
+    ) -> Result<(), MotorGroupError> {
+        let failures: Vec<_> = self
+            .motors
+            .iter_mut()
+            .filter_map(|motor| command(motor).err().map(|err| (motor.port_index(), err)))
+            .collect();
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(MotorGroupError { failures })
+        }
+    }
+
+    /// Reads a value from every motor in the group, collecting the errors of any that fail.
+    fn read_each<T>(
+        &self,
+        read: impl Fn(&Motor) -> Result<T, MotorError>,
+    ) -> Result<Vec<T>, MotorGroupError> {
+        let mut values = Vec::with_capacity(self.motors.len());
+        let mut failures = Vec::new();
+
+        for motor in &self.motors {
+            match read(motor) {
+                Ok(value) => values.push(value),
+                Err(err) => failures.push((motor.port_index(), err)),
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(values)
+        } else {
+            Err(MotorGroupError { failures })
+        }
+    }
+
+    /// Sets the target that every motor in the group should attempt to reach.
+    ///
+    /// See [`Motor::set_target`].
+    pub fn set_target(&mut self, target: MotorControl) -> Result<(), MotorGroupError> {
+        self.for_each(|motor| motor.set_target(target))
+    }
+
+    /// Sets every motor's target to a given [`BrakeMode`].
+    pub fn brake(&mut self, mode: BrakeMode) -> Result<(), MotorGroupError> {
+        self.set_target(MotorControl::Brake(mode))
+    }
+
+    /// Spins every motor in the group at a target velocity.
+    ///
+    /// See [`Motor::set_velocity`].
+    pub fn set_velocity(&mut self, rpm: i32) -> Result<(), MotorGroupError> {
+        self.set_target(MotorControl::Velocity(rpm))
+    }
+
+    /// Sets the output voltage of every motor in the group.
+    ///
+    /// See [`Motor::set_voltage`].
+    pub fn set_voltage(&mut self, volts: f64) -> Result<(), MotorGroupError> {
+        self.set_target(MotorControl::Voltage(volts))
+    }
+
+    /// Sets an absolute position target for every motor in the group.
+    ///
+    /// See [`Motor::set_position_target`].
+    pub fn set_position_target(
+        &mut self,
+        position: Position,
+        velocity: i32,
+    ) -> Result<(), MotorGroupError> {
+        self.set_target(MotorControl::Position(position, velocity))
+    }
+
+    /// Reverses the motors in the group selected by `mask`.
+    ///
+    /// Bit `n` of `mask` corresponds to the motor at index `n` in the group. Motors with their bit
+    /// set are reversed, and all other motors are set to spin forwards.
+    pub fn set_reversed(&mut self, mask: u32) -> Result<(), MotorGroupError> {
+        let mut index = 0;
+        self.for_each(|motor| {
+            let reversed = index < u32::BITS && mask & (1 << index) != 0;
+            index += 1;
+            motor.set_direction(if reversed {
+                Direction::Reverse
+            } else {
+                Direction::Forward
+            })
+        })
+    }
+
+    /// Gets the average estimated angular velocity (RPM) of the motors in the group.
+    pub fn average_velocity(&self) -> Result<f64, MotorGroupError> {
+        let velocities = self.read_each(Motor::velocity)?;
+        Ok(velocities.iter().sum::<f64>() / velocities.len() as f64)
+    }
+
+    /// Gets the average internal temperature of the motors in the group in degrees Celsius.
+    pub fn average_temperature(&self) -> Result<f64, MotorGroupError> {
+        let temperatures = self.read_each(Motor::temperature)?;
+        Ok(temperatures.iter().sum::<f64>() / temperatures.len() as f64)
+    }
+
+    /// Gets the highest electrical current draw of any motor in the group in amps.
+    pub fn max_current(&self) -> Result<f64, MotorGroupError> {
+        let currents = self.read_each(Motor::current)?;
+        Ok(currents.into_iter().fold(0.0, f64::max))
+    }
+
+    /// Gets the total power drawn by the motors in the group in Watts.
+    pub fn total_power(&self) -> Result<f64, MotorGroupError> {
+        Ok(self.read_each(Motor::power)?.iter().sum())
+    }
+
+    /// Returns `true` if any motor in the group has its over temperature flag set.
+    pub fn is_over_temperature(&self) -> Result<bool, MotorGroupError> {
+        Ok(self
+            .read_each(Motor::is_over_temperature)?
+            .into_iter()
+            .any(|flag| flag))
+    }
+
+    /// Returns the number of motors in the group.
+    pub fn len(&self) -> usize {
+        self.motors.len()
+    }
+
+    /// Always returns `false`, since motor groups can't be empty.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns an iterator over the motors in the group.
+    pub fn iter(&self) -> slice::Iter<'_, Motor> {
+        self.motors.iter()
+    }
+
+    /// Returns an iterator that allows modifying each motor in the group.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, Motor> {
+        self.motors.iter_mut()
+    }
+
+    /// Consumes the group and returns the motors inside of it.
+    pub fn into_inner(self) -> Vec<Motor> {
+        self.motors
+    }
+}
+
+impl Index<usize> for MotorGroup {
+    type Output = Motor;
+
+    fn index(&self, index: usize) -> &Motor {
+        &self.motors[index]
+    }
+}
+
+impl IndexMut<usize> for MotorGroup {
+    fn index_mut(&mut self, index: usize) -> &mut Motor {
+        &mut self.motors[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a MotorGroup {
+    type Item = &'a Motor;
+    type IntoIter = slice::Iter<'a, Motor>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut MotorGroup {
+    type Item = &'a mut Motor;
+    type IntoIter = slice::IterMut<'a, Motor>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl TryFrom<Vec<Motor>> for MotorGroup {
+    type Error = Vec<Motor>;
+
+    /// Creates a motor group from the given motors, returning the vector back if it is empty.
+    fn try_from(motors: Vec<Motor>) -> Result<Self, Self::Error> {
+        if motors.is_empty() {
+            Err(motors)
+        } else {
+            Ok(Self { motors })
+        }
+    }
+}
+
+#[derive(Debug, Snafu)]
+#[snafu(display(
+    "{} motor(s) in the group failed, starting with the motor on port {}: {}",
+    failures.len(),
+    failures[0].0,
+    failures[0].1
+))]
+/// Errors that occurred while sending a command to a [`MotorGroup`].
+///
+/// The command was still sent to every motor in the group that did not fail.
+pub struct MotorGroupError {
+    /// Never empty, since an error is only created when at least one motor fails.
+    failures: Vec<(u8, MotorError)>,
+}
+
+impl MotorGroupError {
+    /// Returns the port number and error of every motor that failed.
+    ///
+    /// This always contains at least one failure.
+    pub fn failures(&self) -> &[(u8, MotorError)] {
+        &self.failures
+    }
+
+    /// Consumes the error and returns the port number and error of every motor that failed.
+    pub fn into_failures(self) -> Vec<(u8, MotorError)> {
+        self.failures
+    }
+}
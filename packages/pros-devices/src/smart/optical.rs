@@ -25,9 +25,9 @@ impl OpticalSensor {
     /// The maximum value for the LED PWM.
     pub const MAX_LED_PWM: u8 = 100;
 
-    /// Creates a new inertial sensor from a smart port index.
+    /// Creates a new optical sensor from a smart port index.
     ///
-    /// Gesture detection features can be optionally enabled, allowing the use of [`Self::last_gesture_direction()`] and [`Self::last_gesture_direction()`].
+    /// Gesture detection features can be optionally enabled, allowing the use of [`Self::last_gesture_direction()`] and [`Self::last_gesture_raw()`].
     pub fn new(port: SmartPort, gesture_detection_enabled: bool) -> Result<Self, OpticalError> {
         let mut sensor = Self {
             port,
@@ -83,13 +83,12 @@ impl OpticalSensor {
     /// Lower integration time results in faster update rates with lower accuracy
     /// due to less available light being read by the sensor.
     ///
-    /// Time value must be a [`Duration`] between 3 and 712 milliseconds. See
+    /// The time is clamped to the sensor's supported range of [`Self::MIN_INTEGRATION_TIME`]
+    /// to [`Self::MAX_INTEGRATION_TIME`]. See
     /// <https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9> for
     /// more information.
     pub fn set_integration_time(&mut self, time: Duration) -> Result<(), OpticalError> {
-        if time < Self::MIN_INTEGRATION_TIME || time > Self::MAX_INTEGRATION_TIME {
-            return Err(OpticalError::InvalidIntegrationTime);
-        }
+        let time = time.clamp(Self::MIN_INTEGRATION_TIME, Self::MAX_INTEGRATION_TIME);
 
         unsafe {
             bail_on!(
@@ -137,16 +136,14 @@ impl OpticalSensor {
         }
     }
 
-    /// Get the detected proximity value
+    /// Get the detected proximity value.
     ///
-    /// Proximity has a range of `0` to `255`.
-    pub fn proximity(&self) -> Result<i32, OpticalError> {
-        unsafe {
-            Ok(bail_on!(
-                PROS_ERR,
-                pros_sys::optical_get_proximity(self.port.index())
-            ))
-        }
+    /// Proximity has a range of `0` to `1.0`, with higher values meaning that an object is closer.
+    pub fn proximity(&self) -> Result<f64, OpticalError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::optical_get_proximity(self.port.index())
+        }) as f64
+            / 255.0)
     }
 
     /// Get the processed RGBC data from the sensor
@@ -161,7 +158,7 @@ impl OpticalSensor {
 
     /// Enables gesture detection features on the sensor.
     ///
-    /// This allows [`Self::last_gesture_direction()`] and [`Self::last_gesture_direction()`] to be called without error, if
+    /// This allows [`Self::last_gesture_direction()`] and [`Self::last_gesture_raw()`] to be called without error, if
     /// gesture detection wasn't already enabled.
     pub fn enable_gesture_detection(&mut self) -> Result<(), OpticalError> {
         bail_on!(PROS_ERR, unsafe {
@@ -350,11 +347,6 @@ pub enum OpticalError {
     /// Invalid LED PWM value, must be between 0 and 100.
     InvalidLedPwm,
 
-    /// Integration time must be between 3 and 712 milliseconds.
-    ///
-    /// See <https://www.vexforum.com/t/v5-optical-sensor-refresh-rate/109632/9> for more information.
-    InvalidIntegrationTime,
-
     /// Gesture detection is not enabled for this sensor.
     GestureDetectionDisabled,
 
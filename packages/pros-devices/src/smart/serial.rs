@@ -0,0 +1,638 @@
+//! Generic serial device module.
+//!
+//! Provides support for using [`SmartPort`]s as generic serial communication devices.
+//!
+//! Data is read and written through fixed-size FIFO buffers managed by VEXos. Reads never
+//! block waiting for data to arrive; they only return bytes that are already buffered.
+
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "async")]
+use core::time::Duration;
+
+use no_std_io::io;
+use pros_core::{bail_on, error::PortError, map_errno};
+use pros_sys::PROS_ERR;
+use snafu::Snafu;
+
+use super::{SmartDevice, SmartDeviceType, SmartPort};
+
+/// Represents a smart port configured as a generic serial device.
+#[derive(Debug, Eq, PartialEq)]
+pub struct SerialPort {
+    port: SmartPort,
+    baud_rate: u32,
+}
+
+impl SerialPort {
+    /// The maximum baud rate supported by V5 smart ports.
+    pub const MAX_BAUD_RATE: u32 = 921600;
+
+    /// Open and configure a generic serial port on a [`SmartPort`].
+    ///
+    /// This configures a [`SmartPort`] to act as a generic serial device, capable of sending/receiving
+    /// data at the given baud rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialError::InvalidBaudRate`] if `baud_rate` is zero or greater than
+    /// [`SerialPort::MAX_BAUD_RATE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let serial = SerialPort::open(peripherals.port_1, 115200)?;
+    /// ```
+    pub fn open(port: SmartPort, baud_rate: u32) -> Result<Self, SerialError> {
+        unsafe {
+            bail_on!(PROS_ERR, pros_sys::serial_enable(port.index()));
+        }
+
+        let mut serial = Self { port, baud_rate };
+        serial.set_baud_rate(baud_rate)?;
+
+        Ok(serial)
+    }
+
+    /// Change the baud rate of the serial port.
+    ///
+    /// The port stays open while the baud rate is changed, so buffered data is not lost.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialError::InvalidBaudRate`] if `baud_rate` is zero or greater than
+    /// [`SerialPort::MAX_BAUD_RATE`].
+    pub fn set_baud_rate(&mut self, baud_rate: u32) -> Result<(), SerialError> {
+        if baud_rate == 0 || baud_rate > Self::MAX_BAUD_RATE {
+            return Err(SerialError::InvalidBaudRate { baud_rate });
+        }
+
+        unsafe {
+            bail_on!(
+                PROS_ERR,
+                pros_sys::serial_set_baudrate(self.port.index(), baud_rate as i32)
+            );
+        }
+        self.baud_rate = baud_rate;
+
+        Ok(())
+    }
+
+    /// Returns the baud rate that the serial port was last configured with.
+    pub const fn baud_rate(&self) -> u32 {
+        self.baud_rate
+    }
+
+    /// Read the next byte available in the port's input buffer, or `None` if the input buffer is empty.
+    pub fn read_byte(&mut self) -> Result<Option<u8>, SerialError> {
+        let byte = bail_on!(PROS_ERR, unsafe {
+            pros_sys::serial_read_byte(self.port.index())
+        });
+
+        Ok(match byte {
+            -1 => None,
+            _ => Some(byte as u8),
+        })
+    }
+
+    /// Read the next byte available in the port's input buffer without removing it. Returns
+    /// `None` if the input buffer is empty.
+    pub fn peek_byte(&self) -> Result<Option<u8>, SerialError> {
+        let byte = bail_on!(PROS_ERR, unsafe {
+            pros_sys::serial_peek_byte(self.port.index())
+        });
+
+        Ok(match byte {
+            -1 => None,
+            _ => Some(byte as u8),
+        })
+    }
+
+    /// Read the bytes that are currently buffered in the port's input buffer into `buf`.
+    ///
+    /// At most `buf.len()` bytes are read. This function never blocks waiting for more data
+    /// to arrive, so it may read fewer bytes than requested. A return value of `0` means that
+    /// nothing is buffered right now, not that the connection has been closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut serial = SerialPort::open(peripherals.port_1, 115200)?;
+    ///
+    /// let mut buffer = [0; 64];
+    /// let read = serial.read_available(&mut buffer)?;
+    /// println!("Received {:?}", &buffer[..read]);
+    /// ```
+    pub fn read_available(&mut self, buf: &mut [u8]) -> Result<usize, SerialError> {
+        let bytes_read = bail_on!(PROS_ERR, unsafe {
+            pros_sys::serial_read(self.port.index(), buf.as_mut_ptr(), buf.len() as i32)
+        });
+
+        Ok(bytes_read as usize)
+    }
+
+    /// Write a single byte to the port's output buffer.
+    pub fn write_byte(&mut self, byte: u8) -> Result<(), SerialError> {
+        unsafe {
+            bail_on!(
+                PROS_ERR,
+                pros_sys::serial_write_byte(self.port.index(), byte)
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Write as many bytes from `buf` as will fit in the port's output buffer,
+    /// returning the number of bytes written.
+    ///
+    /// Data in the output buffer is sent as soon as possible on a FIFO basis.
+    pub fn write_available(&mut self, buf: &[u8]) -> Result<usize, SerialError> {
+        let bytes_written = bail_on!(PROS_ERR, unsafe {
+            // The SDK doesn't mutate the buffer despite taking a mutable pointer.
+            pros_sys::serial_write(self.port.index(), buf.as_ptr().cast_mut(), buf.len() as i32)
+        });
+
+        Ok(bytes_written as usize)
+    }
+
+    /// Returns the number of bytes available to be read in the the port's input buffer.
+    pub fn bytes_to_read(&self) -> Result<usize, SerialError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::serial_get_read_avail(self.port.index())
+        }) as usize)
+    }
+
+    /// Returns the number of bytes free in the port's output buffer.
+    pub fn available_write_bytes(&self) -> Result<usize, SerialError> {
+        Ok(bail_on!(PROS_ERR, unsafe {
+            pros_sys::serial_get_write_free(self.port.index())
+        }) as usize)
+    }
+
+    /// Clears the internal input and output buffers.
+    ///
+    /// This discards any unread data as well as any data that has not yet been sent.
+    pub fn clear_buffers(&mut self) -> Result<(), SerialError> {
+        unsafe {
+            bail_on!(PROS_ERR, pros_sys::serial_flush(self.port.index()));
+        }
+
+        Ok(())
+    }
+}
+
+/// How often the async serial methods check the port's buffers while waiting.
+#[cfg(feature = "async")]
+const ASYNC_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Async versions of the serial methods, which wait for data without blocking the executor.
+///
+/// While waiting, these check the port's buffers every millisecond by sleeping with the async
+/// runtime's reactor, so other futures keep running in the meantime.
+#[cfg(feature = "async")]
+impl SerialPort {
+    /// Reads bytes into `buf`, waiting until at least one byte is available.
+    ///
+    /// Returns the number of bytes read, which may be fewer than `buf.len()`.
+    /// Returns `Ok(0)` immediately if `buf` is empty.
+    pub async fn read_async(&mut self, buf: &mut [u8]) -> Result<usize, SerialError> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            if self.bytes_to_read()? > 0 {
+                return self.read_available(buf);
+            }
+            pros_async::sleep(ASYNC_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Reads exactly enough bytes to fill `buf`, waiting for more data to arrive as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialError::TimedOut`] if `buf` hasn't been filled within `timeout`.
+    /// Any bytes that were read before the timeout are left in `buf` but are no longer
+    /// in the port's input buffer.
+    pub async fn read_exact_async(
+        &mut self,
+        buf: &mut [u8],
+        timeout: Duration,
+    ) -> Result<(), SerialError> {
+        let read = async {
+            let mut filled = 0;
+            while filled < buf.len() {
+                filled += self.read_async(&mut buf[filled..]).await?;
+            }
+            Ok(())
+        };
+
+        pros_async::timeout(timeout, read)
+            .await
+            .unwrap_or(Err(SerialError::TimedOut))
+    }
+
+    /// Writes all of `buf` to the port, waiting for space in the output buffer as needed.
+    pub async fn write_all_async(&mut self, mut buf: &[u8]) -> Result<(), SerialError> {
+        while !buf.is_empty() {
+            if self.available_write_bytes()? > 0 {
+                let bytes_written = self.write_available(buf)?;
+                buf = &buf[bytes_written..];
+            } else {
+                pros_async::sleep(ASYNC_POLL_INTERVAL).await;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl io::Read for SerialPort {
+    /// Read the currently buffered bytes into `buf`.
+    ///
+    /// Like [`SerialPort::read_available`], this does not block waiting for data,
+    /// so `Ok(0)` is returned when the input buffer is empty.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Ok(self.read_available(buf)?)
+    }
+}
+
+impl io::Write for SerialPort {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(self.write_available(buf)?)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // The output buffer is sent as soon as possible, so there's nothing to flush.
+        Ok(())
+    }
+}
+
+/// Reads newline-delimited lines of text or other framed data from a [`SerialPort`].
+///
+/// Incoming bytes are buffered until a full line has been received, so lines that
+/// arrive across several reads are reassembled. Both `\n` and `\r\n` line endings
+/// are supported. Frames ending in another delimiter or with a fixed length can be read with
+/// [`LineReader::next_frame`] and [`LineReader::next_exact_frame`].
+///
+/// The same buffer is reused for every read, and it never grows much past the maximum line length.
+///
+/// # Examples
+///
+/// ```
+/// let serial = SerialPort::open(peripherals.port_1, 115200)?;
+/// let mut reader = LineReader::new(serial);
+///
+/// loop {
+///     while let Some(line) = reader.next_line()? {
+///         println!("Received: {line}");
+///     }
+///     sleep(Duration::from_millis(10)).await;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct LineReader {
+    serial: SerialPort,
+    buffer: Vec<u8>,
+    max_line_length: usize,
+    discarding: bool,
+}
+
+impl LineReader {
+    /// The default maximum length of a line in bytes, excluding the line ending.
+    pub const DEFAULT_MAX_LINE_LENGTH: usize = 256;
+
+    /// Create a new line reader over a serial port using [`LineReader::DEFAULT_MAX_LINE_LENGTH`].
+    pub const fn new(serial: SerialPort) -> Self {
+        Self::with_max_line_length(serial, Self::DEFAULT_MAX_LINE_LENGTH)
+    }
+
+    /// Create a new line reader over a serial port that accepts lines up to
+    /// `max_line_length` bytes long, excluding the line ending.
+    pub const fn with_max_line_length(serial: SerialPort, max_line_length: usize) -> Self {
+        Self {
+            serial,
+            buffer: Vec::new(),
+            max_line_length,
+            discarding: false,
+        }
+    }
+
+    /// Returns the next complete line, or `None` if no complete line has been received yet.
+    ///
+    /// This never blocks waiting for data. The returned line does not include its line ending.
+    /// Lines end at `\n`, and a single `\r` directly before it is also removed. Any other `\r`
+    /// characters are kept. Invalid UTF-8 is replaced with [`char::REPLACEMENT_CHARACTER`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialError::LineTooLong`] if a line exceeds the maximum line length.
+    /// The rest of that line is discarded, and reading continues from the following line.
+    pub fn next_line(&mut self) -> Result<Option<String>, SerialError> {
+        let Some(end) = self.find_delimited(b'\n', true)? else {
+            return Ok(None);
+        };
+
+        let mut line_end = end - 1;
+        if line_end > 0 && self.buffer[line_end - 1] == b'\r' {
+            line_end -= 1;
+        }
+
+        let line = String::from_utf8_lossy(&self.buffer[..line_end]).into_owned();
+        self.buffer.drain(..end);
+
+        Ok(Some(line))
+    }
+
+    /// Returns the next complete frame ending in `delimiter`, or `None` if no complete frame
+    /// has been received yet.
+    ///
+    /// This never blocks waiting for data. The returned frame does not include the delimiter,
+    /// and no other bytes (such as `\r`) are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialError::LineTooLong`] if a frame exceeds the maximum line length.
+    /// The rest of that frame is discarded, and reading continues from the following frame.
+    pub fn next_frame(&mut self, delimiter: u8) -> Result<Option<Vec<u8>>, SerialError> {
+        let Some(end) = self.find_delimited(delimiter, false)? else {
+            return Ok(None);
+        };
+
+        let frame = self.buffer[..end - 1].to_vec();
+        self.buffer.drain(..end);
+
+        Ok(Some(frame))
+    }
+
+    /// Returns the next `len` bytes, or `None` if fewer than `len` bytes have been received so far.
+    ///
+    /// This is useful for fixed-size or length-prefixed frames. Bytes are never discarded,
+    /// so a partially received frame is completed by later calls.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerialError::LineTooLong`] if `len` is greater than the maximum line length.
+    pub fn next_exact_frame(&mut self, len: usize) -> Result<Option<Vec<u8>>, SerialError> {
+        if len > self.max_line_length {
+            return Err(SerialError::LineTooLong);
+        }
+
+        while self.buffer.len() < len {
+            if !self.fill(len)? {
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(self.buffer.drain(..len).collect()))
+    }
+
+    /// Returns the length of the next buffered frame ending in `delimiter` (including the delimiter),
+    /// reading more data from the serial port if needed.
+    ///
+    /// If `strip_cr` is set, a `\r` directly before the delimiter is part of the line ending and
+    /// doesn't count towards the maximum line length.
+    fn find_delimited(
+        &mut self,
+        delimiter: u8,
+        strip_cr: bool,
+    ) -> Result<Option<usize>, SerialError> {
+        // Bytes before `searched` are already known not to contain the delimiter.
+        let mut searched = 0;
+        loop {
+            if let Some(position) = self.buffer[searched..]
+                .iter()
+                .position(|&byte| byte == delimiter)
+            {
+                let end = searched + position + 1;
+
+                if self.discarding {
+                    self.buffer.drain(..end);
+                    self.discarding = false;
+                    searched = 0;
+                    continue;
+                }
+
+                let mut line_len = end - 1;
+                if strip_cr && line_len > 0 && self.buffer[line_len - 1] == b'\r' {
+                    line_len -= 1;
+                }
+                if line_len > self.max_line_length {
+                    self.buffer.drain(..end);
+                    return Err(SerialError::LineTooLong);
+                }
+
+                return Ok(Some(end));
+            }
+
+            // A trailing `\r` may be the start of a `\r\n` line ending whose `\n` hasn't arrived yet.
+            let max_len = if strip_cr && self.buffer.last() == Some(&b'\r') {
+                self.max_line_length + 1
+            } else {
+                self.max_line_length
+            };
+
+            if self.discarding {
+                self.buffer.clear();
+            } else if self.buffer.len() > max_len {
+                self.buffer.clear();
+                self.discarding = true;
+                return Err(SerialError::LineTooLong);
+            }
+            searched = self.buffer.len();
+
+            // Read at most enough to go one byte over the limit (including a possible `\r`),
+            // so that the buffer can't grow unbounded.
+            if !self.fill(self.max_line_length + 1 + usize::from(strip_cr))? {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Reads available bytes into the buffer until it is at most `target_len` bytes long.
+    ///
+    /// Returns `false` if no bytes were available.
+    fn fill(&mut self, target_len: usize) -> Result<bool, SerialError> {
+        let mut chunk = [0; 64];
+        let limit = target_len
+            .saturating_sub(self.buffer.len())
+            .min(chunk.len());
+        let bytes_read = self.serial.read_available(&mut chunk[..limit])?;
+        self.buffer.extend_from_slice(&chunk[..bytes_read]);
+
+        Ok(bytes_read > 0)
+    }
+
+    /// Returns a reference to the underlying serial port.
+    pub const fn get_ref(&self) -> &SerialPort {
+        &self.serial
+    }
+
+    /// Returns a mutable reference to the underlying serial port.
+    ///
+    /// Reading from the serial port directly may cause lines to be lost.
+    pub fn get_mut(&mut self) -> &mut SerialPort {
+        &mut self.serial
+    }
+
+    /// Consumes the line reader, returning the underlying serial port.
+    ///
+    /// Any partially received line is discarded.
+    pub fn into_inner(self) -> SerialPort {
+        self.serial
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Error for SerialError {
+    fn kind(&self) -> embedded_io::ErrorKind {
+        match self {
+            Self::Busy
+            | Self::Port {
+                source: PortError::AlreadyInUse,
+            } => embedded_io::ErrorKind::AddrInUse,
+            Self::InvalidPort
+            | Self::Port {
+                source: PortError::PortOutOfRange | PortError::PortCannotBeConfigured,
+            } => embedded_io::ErrorKind::AddrNotAvailable,
+            Self::Internal => embedded_io::ErrorKind::Other,
+            Self::LineTooLong => embedded_io::ErrorKind::InvalidData,
+            Self::TimedOut => embedded_io::ErrorKind::TimedOut,
+            Self::InvalidBaudRate { .. } => embedded_io::ErrorKind::InvalidInput,
+        }
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::ErrorType for SerialPort {
+    type Error = SerialError;
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Read for SerialPort {
+    /// Read buffered bytes into `buf`, blocking until at least one byte is available.
+    ///
+    /// Unlike [`io::Read`], this follows `embedded-io`'s requirement that reads only return
+    /// `Ok(0)` when `buf` is empty.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let bytes_read = self.read_available(buf)?;
+            if bytes_read > 0 {
+                return Ok(bytes_read);
+            }
+            pros_core::task::delay(core::time::Duration::from_millis(1));
+        }
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::ReadReady for SerialPort {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.bytes_to_read()? > 0)
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Write for SerialPort {
+    /// Write bytes from `buf` to the output buffer, blocking until at least one byte can be written.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let bytes_written = self.write_available(buf)?;
+            if bytes_written > 0 {
+                return Ok(bytes_written);
+            }
+            pros_core::task::delay(core::time::Duration::from_millis(1));
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        // The output buffer is sent as soon as possible, so there's nothing to flush.
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::WriteReady for SerialPort {
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.available_write_bytes()? > 0)
+    }
+}
+
+impl SmartDevice for SerialPort {
+    fn port_index(&self) -> u8 {
+        self.port.index()
+    }
+
+    fn device_type(&self) -> SmartDeviceType {
+        SmartDeviceType::Serial
+    }
+}
+
+#[derive(Debug, Snafu)]
+/// Errors that can occur when using a serial port.
+pub enum SerialError {
+    /// Another resource is currently trying to access the port.
+    Busy,
+    /// The given port is not within the range of V5 ports (1-21).
+    InvalidPort,
+    /// A serious internal write error occurred.
+    Internal,
+    /// A line or frame was longer than the maximum line length of a [`LineReader`].
+    LineTooLong,
+    /// The operation did not complete before its timeout elapsed.
+    TimedOut,
+    #[snafu(display(
+        "Baud rate {baud_rate} is not supported. Baud rates must be between 1 and {}.",
+        SerialPort::MAX_BAUD_RATE
+    ))]
+    /// The given baud rate is not supported.
+    InvalidBaudRate {
+        /// The baud rate that was requested.
+        baud_rate: u32,
+    },
+    #[snafu(display("{source}"), context(false))]
+    /// Generic port related error.
+    Port {
+        /// The source of the error.
+        source: PortError,
+    },
+}
+
+impl From<SerialError> for io::Error {
+    fn from(err: SerialError) -> Self {
+        match err {
+            SerialError::Busy => Self::new(io::ErrorKind::AddrInUse, "serial port is busy"),
+            SerialError::InvalidPort => Self::new(
+                io::ErrorKind::AddrNotAvailable,
+                "serial port is not a valid smart port",
+            ),
+            SerialError::Internal => Self::new(io::ErrorKind::Other, "internal serial write error"),
+            SerialError::LineTooLong => Self::new(io::ErrorKind::InvalidData, "line too long"),
+            SerialError::TimedOut => {
+                Self::new(io::ErrorKind::TimedOut, "serial operation timed out")
+            }
+            SerialError::InvalidBaudRate { .. } => {
+                Self::new(io::ErrorKind::InvalidInput, "unsupported baud rate")
+            }
+            SerialError::Port { source } => source.into(),
+        }
+    }
+}
+
+map_errno! {
+    SerialError {
+        EACCES => Self::Busy,
+        EINVAL => Self::InvalidPort,
+        EIO => Self::Internal,
+    }
+    inherit PortError;
+}
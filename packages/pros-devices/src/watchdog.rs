@@ -0,0 +1,127 @@
+//! Motor safety watchdog.
+//!
+//! A [`Watchdog`] stops every motor on the robot if user code stops checking in with it, which
+//! prevents a robot from driving away on its own after an accidental infinite loop or deadlock.
+
+use alloc::sync::Arc;
+use core::{
+    sync::atomic::{AtomicBool, AtomicU32, Ordering},
+    time::Duration,
+};
+
+use pros_core::task::{self, delay, TaskPriority};
+
+use crate::smart::motor::stop_all_motors;
+
+/// The longest amount of time between checks of a watchdog's timer.
+const MAX_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+fn now_millis() -> u32 {
+    unsafe { pros_sys::millis() }
+}
+
+/// The state shared between a [`Watchdog`] and its background task.
+#[derive(Debug)]
+struct WatchdogState {
+    timeout_millis: u32,
+    last_fed: AtomicU32,
+    tripped: AtomicBool,
+    cancelled: AtomicBool,
+}
+
+impl WatchdogState {
+    fn is_starved(&self) -> bool {
+        now_millis().wrapping_sub(self.last_fed.load(Ordering::Relaxed)) > self.timeout_millis
+    }
+}
+
+/// Stops all motors when it isn't [fed](Watchdog::feed) often enough.
+///
+/// The watchdog is checked by a separate FreeRTOS task, so it keeps working even if the task
+/// running user code (including the async executor) is stuck in a loop that never yields.
+/// For as long as the watchdog goes without being fed, [`stop_all_motors`] is called repeatedly
+/// so that motors stay stopped even if the stalled code keeps commanding them.
+///
+/// Dropping the watchdog (or calling [`Watchdog::cancel`]) stops its task.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use pros::devices::watchdog::Watchdog;
+///
+/// let watchdog = Watchdog::new(Duration::from_millis(100));
+/// loop {
+///     watchdog.feed();
+///     // Drive the robot...
+///     pros::core::task::delay(Duration::from_millis(10));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Watchdog {
+    state: Arc<WatchdogState>,
+}
+
+impl Watchdog {
+    /// Creates a new watchdog that stops all motors if it isn't fed within `timeout`.
+    ///
+    /// The watchdog starts out fed, so the first timeout is measured from when it is created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the watchdog's task can't be created.
+    pub fn new(timeout: Duration) -> Self {
+        let timeout_millis = timeout.as_millis().min(i32::MAX as u128) as u32;
+        let state = Arc::new(WatchdogState {
+            timeout_millis,
+            last_fed: AtomicU32::new(now_millis()),
+            tripped: AtomicBool::new(false),
+            cancelled: AtomicBool::new(false),
+        });
+
+        let check_interval = (timeout / 4).clamp(Duration::from_millis(1), MAX_CHECK_INTERVAL);
+        let task_state = state.clone();
+        task::Builder::new()
+            .name("watchdog")
+            // A higher priority ensures the watchdog still runs if user code never yields.
+            .priority(TaskPriority::High)
+            .spawn(move || {
+                while !task_state.cancelled.load(Ordering::Relaxed) {
+                    if task_state.is_starved() {
+                        task_state.tripped.store(true, Ordering::Relaxed);
+                        stop_all_motors();
+                    }
+                    delay(check_interval);
+                }
+            })
+            .expect("Failed to spawn watchdog task");
+
+        Self { state }
+    }
+
+    /// Resets the watchdog's timer.
+    ///
+    /// This should be called on every iteration of a control loop.
+    pub fn feed(&self) {
+        self.state.last_fed.store(now_millis(), Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the watchdog has stopped the motors at least once since it was created.
+    pub fn has_tripped(&self) -> bool {
+        self.state.tripped.load(Ordering::Relaxed)
+    }
+
+    /// Returns the amount of time the watchdog can go without being fed.
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.state.timeout_millis as u64)
+    }
+
+    /// Stops the watchdog. This is the same as dropping it.
+    pub fn cancel(self) {}
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        self.state.cancelled.store(true, Ordering::Relaxed);
+    }
+}
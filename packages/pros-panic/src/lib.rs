@@ -1,17 +1,58 @@
 //! Panic handler implementation for [`pros-rs`](https://crates.io/crates/pros-rs).
 //! Supports printing a backtrace when running in the simulator.
 //! If the `display_panics` feature is enabled, it will also display the panic message on the V5 Brain display.
+//! If the `controller_panics` feature is enabled, the master controller will rumble and show where the panic occurred.
+//!
+//! Custom behavior, such as stopping motors, can be added with [`set_hook`].
 
 #![no_std]
 
 extern crate alloc;
 
-use alloc::{format, string::String};
-
-use pros_core::eprintln;
+#[cfg(feature = "display_panics")]
+use alloc::string::String;
+use alloc::{boxed::Box, format};
+use core::{
+    panic::PanicInfo,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use pros_core::{eprintln, sync::RwLock};
 #[cfg(feature = "display_panics")]
 use pros_devices::Screen;
 
+/// A function that is called when a panic occurs.
+pub type PanicHook = Box<dyn Fn(&PanicInfo<'_>) + Send + Sync>;
+
+static HOOK: RwLock<Option<PanicHook>> = RwLock::new(None);
+
+/// Set when the panic handler starts running, so that a panic inside of it doesn't recurse.
+static PANICKING: AtomicBool = AtomicBool::new(false);
+
+/// Registers a function to be called when a panic occurs, replacing any previously registered hook.
+///
+/// The hook runs after the panic message has been printed, but before it is displayed on the
+/// screen or controller and before the program exits. This makes it a good place to put robots
+/// into a safe state, for example by stopping all motors.
+///
+/// If the hook panics, the program exits immediately without displaying either panic message.
+///
+/// # Examples
+///
+/// ```no_run
+/// pros_panic::set_hook(|_info| {
+///     // Stop the drivetrain motors here.
+/// });
+/// ```
+pub fn set_hook(hook: impl Fn(&PanicInfo<'_>) + Send + Sync + 'static) {
+    *HOOK.write() = Some(Box::new(hook));
+}
+
+/// Removes the hook registered with [`set_hook`], returning it if there was one.
+pub fn take_hook() -> Option<PanicHook> {
+    HOOK.write().take()
+}
+
 #[cfg(target_arch = "wasm32")]
 extern "C" {
     /// Prints a backtrace to the debug console
@@ -82,9 +123,50 @@ fn draw_error(
     Ok(())
 }
 
+/// Rumbles the master controller and prints the file and line that panicked on its display.
+#[cfg(feature = "controller_panics")]
+fn notify_controller(info: &PanicInfo<'_>) {
+    use pros_core::task::delay;
+    use pros_devices::controller::{Controller, ControllerLine};
+
+    let controller = Controller::Master;
+    if !matches!(controller.is_connected(), Ok(true)) {
+        return;
+    }
+
+    _ = controller.rumble("---");
+
+    if let Some(location) = info.location() {
+        let file = location.file().rsplit('/').next().unwrap_or_default();
+        let text = format!("{file}:{}", location.line());
+        // Keep the end of the text, since the line number is the most useful part. The display
+        // limit is in bytes, so cut at the first char boundary that fits rather than slicing
+        // mid-char, which would panic and skip the rest of the panic handler.
+        let start = (text.len().saturating_sub(ControllerLine::MAX_TEXT_LEN)..text.len())
+            .find(|&index| text.is_char_boundary(index))
+            .unwrap_or(text.len());
+        let text = &text[start..];
+
+        // The controller drops writes that are sent too close together, so wait for the
+        // rumble to go through before printing, and for the text to go through before exiting.
+        delay(ControllerLine::MIN_WRITE_INTERVAL);
+        _ = controller.line(0).try_print(format!(
+            "{text}{:width$}",
+            "",
+            width = ControllerLine::MAX_TEXT_LEN - text.len()
+        ));
+        delay(ControllerLine::MIN_WRITE_INTERVAL);
+    }
+}
+
 #[panic_handler]
 /// The panic handler for pros-rs.
-pub fn panic(info: &core::panic::PanicInfo<'_>) -> ! {
+pub fn panic(info: &PanicInfo<'_>) -> ! {
+    if PANICKING.swap(true, Ordering::Relaxed) {
+        eprintln!("panicked while handling a panic: {info}");
+        unsafe { pros_sys::exit(1) }
+    }
+
     let current_task = pros_core::task::current();
 
     let task_name = current_task.name().unwrap_or_else(|_| "<unknown>".into());
@@ -93,7 +175,15 @@ pub fn panic(info: &core::panic::PanicInfo<'_>) -> ! {
     // panic message here
     let msg = format!("task '{task_name}' {info}");
 
-    eprintln!("{msg}");
+    let millis = unsafe { pros_sys::millis() };
+    eprintln!("[{millis}ms] {msg}");
+
+    if let Some(hook) = HOOK.try_read().as_deref().and_then(Option::as_ref) {
+        hook(info);
+    }
+
+    #[cfg(feature = "controller_panics")]
+    notify_controller(info);
 
     unsafe {
         #[cfg(feature = "display_panics")]
@@ -20,6 +20,7 @@ pub const E_DEVICE_RADIO: v5_device_e_t = 8;
 pub const E_DEVICE_VISION: v5_device_e_t = 11;
 pub const E_DEVICE_ADI: v5_device_e_t = 12;
 pub const E_DEVICE_OPTICAL: v5_device_e_t = 16;
+pub const E_DEVICE_MAGNET: v5_device_e_t = 17;
 pub const E_DEVICE_GPS: v5_device_e_t = 20;
 pub const E_DEVICE_SERIAL: v5_device_e_t = 129;
 #[deprecated(note = "use E_DEVICE_SERIAL instead")]
@@ -22,7 +22,7 @@ pub mod rtos;
 pub mod screen;
 pub mod vision;
 
-use core::ffi::{c_char, c_int, c_void};
+use core::ffi::{c_char, c_int, c_long, c_void};
 
 pub use adi::*;
 pub use colors::*;
@@ -38,14 +38,21 @@ pub use optical::*;
 pub use rotation::*;
 pub use rtos::*;
 pub use screen::*;
-#[cfg(feaute = "apix")]
+#[cfg(feature = "xapi")]
 pub use serial::*;
 pub use vision::*;
-#[cfg(feaute = "apix")]
+#[cfg(feature = "xapi")]
 pub mod serial;
 
 pub const CLOCKS_PER_SEC: u32 = 1000;
 
+/// An open file stream from newlib's stdio.
+pub type FILE = c_void;
+
+pub const SEEK_SET: c_int = 0;
+pub const SEEK_CUR: c_int = 1;
+pub const SEEK_END: c_int = 2;
+
 extern "C" {
     #[cfg(not(target_arch = "wasm32"))]
     pub fn memalign(alignment: usize, size: usize) -> *mut c_void;
@@ -56,6 +63,15 @@ extern "C" {
     pub fn puts(s: *const c_char) -> i32;
     pub fn exit(code: i32) -> !;
     pub fn write(fd: c_int, buf: *const c_void, count: usize) -> isize;
+    pub fn fopen(path: *const c_char, mode: *const c_char) -> *mut FILE;
+    pub fn fclose(stream: *mut FILE) -> c_int;
+    pub fn fread(ptr: *mut c_void, size: usize, count: usize, stream: *mut FILE) -> usize;
+    pub fn fwrite(ptr: *const c_void, size: usize, count: usize, stream: *mut FILE) -> usize;
+    pub fn fflush(stream: *mut FILE) -> c_int;
+    pub fn fseek(stream: *mut FILE, offset: c_long, whence: c_int) -> c_int;
+    pub fn ftell(stream: *mut FILE) -> c_long;
+    pub fn ferror(stream: *mut FILE) -> c_int;
+    pub fn clearerr(stream: *mut FILE);
 
     fn initialize();
     fn opcontrol();
@@ -457,7 +457,7 @@ extern "C" {
     \return The raw encoder count at the given timestamp or PROS_ERR if the
     operation failed.
     */
-    pub fn motor_get_raw_position(port: i8, timestamp: *const u32) -> i32;
+    pub fn motor_get_raw_position(port: i8, timestamp: *mut u32) -> i32;
     /**
     Gets the absolute position of the motor in its encoder units.
 
@@ -24,7 +24,10 @@ impl AsyncRobot for Robot {
     async fn opcontrol(&mut self) -> Result {
         // Read from the encoder every second.
         loop {
-            println!("Encoder position: {}", self.encoder.position()?);
+            println!(
+                "Encoder position: {} degrees",
+                self.encoder.position()?.into_degrees()
+            );
 
             delay(Duration::from_secs(1));
         }
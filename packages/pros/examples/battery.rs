@@ -1,6 +1,11 @@
 #![no_std]
 #![no_main]
 
+extern crate alloc;
+
+use alloc::format;
+use core::time::Duration;
+
 use pros::{devices::battery, prelude::*};
 
 #[derive(Default)]
@@ -14,7 +19,18 @@ impl AsyncRobot for Robot {
             println!("Battery has exploded!");
         }
 
-        Ok(())
+        let controller = Controller::Master;
+        loop {
+            let status = battery::status()?;
+
+            // Errors are ignored since the controller may not be connected.
+            _ = controller.line(0).try_print(format!(
+                "Bat {:>3.0}% {:>4.1}V",
+                status.capacity, status.voltage
+            ));
+
+            sleep(Duration::from_secs(1)).await;
+        }
     }
 }
 async_robot!(Robot);
@@ -1,7 +1,9 @@
 #![no_std]
 #![no_main]
 
-use pros::prelude::*;
+use core::time::Duration;
+
+use pros::{devices::smart::SerialPort, prelude::*};
 
 pub struct Robot {
     peripherals: DynamicPeripherals,
@@ -15,12 +17,23 @@ impl Robot {
 }
 impl AsyncRobot for Robot {
     async fn opcontrol(&mut self) -> Result {
-        let motor = Motor::new(
+        let mut motor = Motor::new(
             self.peripherals.take_smart_port(10).unwrap(),
             Gearset::Green,
-            false,
+            Direction::Forward,
         )?;
-        motor.wait_until_stopped().await?;
+        motor.set_voltage(6.0)?;
+        sleep(Duration::from_secs(1)).await;
+        motor.brake(BrakeMode::Brake)?;
+
+        // The port can't be taken again while the motor is using it.
+        assert!(self.peripherals.take_smart_port(10).is_none());
+
+        // Drop the motor and give its port back, so that it can be reused for a serial device.
+        self.peripherals.reclaim_smart_port(motor);
+        let mut serial = SerialPort::open(self.peripherals.take_smart_port(10).unwrap(), 115200)?;
+        serial.write_byte(b'!')?;
+
         Ok(())
     }
 }
@@ -0,0 +1,81 @@
+#![no_std]
+#![no_main]
+
+use core::time::Duration;
+
+use pros::prelude::*;
+
+pub struct Robot {
+    gps: GpsSensor,
+    left: Motor,
+    right: Motor,
+}
+impl Robot {
+    fn new(peripherals: Peripherals) -> Self {
+        Self {
+            // The sensor is mounted 0.1m behind the center of turning,
+            // and the robot starts in the bottom left corner facing north.
+            gps: GpsSensor::new(
+                peripherals.port_1,
+                0.0,
+                -0.1,
+                GpsPose {
+                    x: -1.5,
+                    y: -1.5,
+                    heading: 0.0,
+                },
+            )
+            .unwrap(),
+            left: Motor::new(peripherals.port_2, Gearset::Green, Direction::Forward).unwrap(),
+            right: Motor::new(peripherals.port_3, Gearset::Green, Direction::Reverse).unwrap(),
+        }
+    }
+}
+
+impl AsyncRobot for Robot {
+    async fn opcontrol(&mut self) -> Result {
+        let controller = Controller::Master;
+
+        loop {
+            let joystick = controller.state()?.joysticks.left.with_deadzone(0.05);
+            let pose = self.gps.pose()?;
+
+            // The joystick points in field directions (up is north), so find the field
+            // heading it points towards and how far the robot is turned away from it.
+            let magnitude = libm::hypotf(joystick.x, joystick.y) as f64;
+            let target_heading = libm::atan2(joystick.x as f64, joystick.y as f64).to_degrees();
+            let mut error = target_heading - pose.heading;
+            while error > 180.0 {
+                error -= 360.0;
+            }
+            while error < -180.0 {
+                error += 360.0;
+            }
+
+            // Drive forward while turning towards the joystick direction.
+            let (forward, turn) = if magnitude > 0.05 {
+                (magnitude * libm::cos(error.to_radians()), error / 90.0)
+            } else {
+                (0.0, 0.0)
+            };
+            let turn = turn.clamp(-1.0, 1.0);
+
+            self.left
+                .set_voltage((forward + turn).clamp(-1.0, 1.0) * 12.0)?;
+            self.right
+                .set_voltage((forward - turn).clamp(-1.0, 1.0) * 12.0)?;
+
+            println!(
+                "x: {:.2}m y: {:.2}m heading: {:.1} error: {:.3}m",
+                pose.x,
+                pose.y,
+                pose.heading,
+                self.gps.error()?
+            );
+
+            sleep(Duration::from_millis(10)).await;
+        }
+    }
+}
+
+async_robot!(Robot, Robot::new(Peripherals::take().unwrap()));
@@ -0,0 +1,37 @@
+#![no_std]
+#![no_main]
+
+use core::time::Duration;
+
+use pros::prelude::*;
+
+/// The receiving side of a VEXLink connection. Run `link_tx` on the other robot.
+pub struct Robot {
+    link: RxLink,
+}
+
+impl Robot {
+    fn new(peripherals: Peripherals) -> Self {
+        Self {
+            link: RxLink::new(peripherals.port_1, "example_link".into(), false).unwrap(),
+        }
+    }
+}
+
+impl AsyncRobot for Robot {
+    async fn opcontrol(&mut self) -> Result {
+        let mut buf = [0; 4];
+        loop {
+            if self.link.connected() {
+                match self.link.receive(&mut buf) {
+                    Ok(_) => println!("Received {}", u32::from_le_bytes(buf)),
+                    Err(LinkError::Busy) => {}
+                    Err(err) => println!("Failed to receive: {err}"),
+                }
+            }
+
+            sleep(Duration::from_millis(100)).await;
+        }
+    }
+}
+async_robot!(Robot, Robot::new(Peripherals::take().unwrap()));
@@ -0,0 +1,36 @@
+#![no_std]
+#![no_main]
+
+use core::time::Duration;
+
+use pros::prelude::*;
+
+/// The transmitting side of a VEXLink connection. Run `link_rx` on the other robot.
+pub struct Robot {
+    link: TxLink,
+}
+
+impl Robot {
+    fn new(peripherals: Peripherals) -> Self {
+        Self {
+            link: TxLink::new(peripherals.port_1, "example_link".into(), false).unwrap(),
+        }
+    }
+}
+
+impl AsyncRobot for Robot {
+    async fn opcontrol(&mut self) -> Result {
+        let mut count: u32 = 0;
+        loop {
+            if self.link.connected() {
+                match self.link.transmit(&count.to_le_bytes()) {
+                    Ok(_) => count += 1,
+                    Err(err) => println!("Failed to transmit: {err}"),
+                }
+            }
+
+            sleep(Duration::from_millis(100)).await;
+        }
+    }
+}
+async_robot!(Robot, Robot::new(Peripherals::take().unwrap()));
@@ -0,0 +1,92 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{format, string::String, vec::Vec};
+use core::time::Duration;
+
+use pros::{core::fs::File, prelude::*};
+
+const RECORDING_PATH: &str = "/usd/recording.csv";
+const LOOP_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Records the driver's joystick inputs to the SD card during opcontrol,
+/// then plays them back during autonomous.
+///
+/// Press A on the controller to stop recording.
+pub struct Robot {
+    left: Motor,
+    right: Motor,
+}
+
+impl Robot {
+    fn new(peripherals: Peripherals) -> Self {
+        Self {
+            left: Motor::new(peripherals.port_1, Gearset::Green, Direction::Forward).unwrap(),
+            right: Motor::new(peripherals.port_2, Gearset::Green, Direction::Reverse).unwrap(),
+        }
+    }
+}
+
+impl AsyncRobot for Robot {
+    async fn opcontrol(&mut self) -> Result {
+        let controller = Controller::Master;
+        let mut recording = File::create(RECORDING_PATH)?;
+
+        loop {
+            let state = controller.state()?;
+            let (left, right) = (state.joysticks.left.y, state.joysticks.right.y);
+
+            self.left.set_voltage(Motor::MAX_VOLTAGE * left as f64)?;
+            self.right.set_voltage(Motor::MAX_VOLTAGE * right as f64)?;
+
+            if state.buttons.a {
+                break;
+            }
+            if let Err(err) = recording.write_all(format!("{left},{right}\n").as_bytes()) {
+                println!("Failed to record inputs: {err:?}");
+                break;
+            }
+
+            sleep(LOOP_INTERVAL).await;
+        }
+
+        // Dropping the file would also flush it, but this lets us report errors.
+        match recording.flush() {
+            Ok(()) => println!("Recording saved to {RECORDING_PATH}"),
+            Err(err) => println!("Failed to save recording: {err:?}"),
+        }
+
+        Ok(())
+    }
+
+    async fn auto(&mut self) -> Result {
+        let mut recording = Vec::new();
+        if let Err(err) = File::open(RECORDING_PATH)?.read_to_end(&mut recording) {
+            println!("Failed to read recording: {err:?}");
+            return Ok(());
+        }
+        let recording = String::from_utf8(recording)?;
+
+        for line in recording.lines() {
+            let Some((left, right)) = line.split_once(',') else {
+                continue;
+            };
+            let (Ok(left), Ok(right)) = (left.parse::<f64>(), right.parse::<f64>()) else {
+                continue;
+            };
+
+            self.left.set_voltage(Motor::MAX_VOLTAGE * left)?;
+            self.right.set_voltage(Motor::MAX_VOLTAGE * right)?;
+
+            sleep(LOOP_INTERVAL).await;
+        }
+
+        self.left.brake(BrakeMode::Brake)?;
+        self.right.brake(BrakeMode::Brake)?;
+
+        Ok(())
+    }
+}
+async_robot!(Robot, Robot::new(Peripherals::take().unwrap()));
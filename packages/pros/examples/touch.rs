@@ -0,0 +1,41 @@
+#![no_std]
+#![no_main]
+
+use pros::prelude::*;
+
+const BUTTON: (i16, i16, i16, i16) = (180, 80, 300, 160);
+
+pub struct Robot {
+    screen: Screen,
+    touch: TouchScreen,
+}
+
+impl Robot {
+    fn new(peripherals: Peripherals) -> Self {
+        let screen = peripherals.screen;
+        Self {
+            touch: screen.touch_screen(),
+            screen,
+        }
+    }
+}
+
+impl AsyncRobot for Robot {
+    async fn opcontrol(&mut self) -> Result {
+        let (x0, y0, x1, y1) = BUTTON;
+        self.screen.fill(&Rect::new(x0, y0, x1, y1), Rgb::BLUE)?;
+        self.screen
+            .print_at(x0 + 20, y0 + 30, TextFormat::Medium, "Press me", Rgb::WHITE)?;
+
+        let mut presses = 0;
+        loop {
+            let touch = self.touch.wait_for_touch().await?;
+
+            if (x0..=x1).contains(&touch.x) && (y0..=y1).contains(&touch.y) {
+                presses += 1;
+                println!("Button pressed {presses} times");
+            }
+        }
+    }
+}
+async_robot!(Robot, Robot::new(Peripherals::take().unwrap()));
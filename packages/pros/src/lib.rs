@@ -99,14 +99,17 @@ pub mod prelude {
         controller::Controller,
         peripherals::{DynamicPeripherals, Peripherals},
         position::Position,
-        screen::{Circle, Line, Rect, Screen, Text, TextFormat, TextPosition, TouchState},
+        screen::{
+            Circle, Line, Rect, Screen, Text, TextFormat, TextPosition, TouchScreen, TouchState,
+        },
         smart::{
             distance::DistanceSensor,
             expander::AdiExpander,
-            gps::GpsSensor,
+            gps::{GpsPose, GpsSensor},
             imu::InertialSensor,
-            link::{Link, RxLink, TxLink},
+            link::{Link, LinkError, RxLink, TxLink},
             motor::{BrakeMode, Direction, Gearset, Motor, MotorControl},
+            motor_group::MotorGroup,
             optical::OpticalSensor,
             rotation::RotationSensor,
             vision::VisionSensor,